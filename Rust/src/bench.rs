@@ -0,0 +1,118 @@
+//! Wall time measurements of the stages of a solution.
+
+use std::time::{Duration, Instant};
+
+use crate::Error;
+
+/// Wall time statistics of a stage that was run several times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Computes the statistics of the given samples. For an even number of
+    /// samples the lower of the two middle samples is taken as the median.
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Stats> {
+        samples.sort();
+
+        Some(Stats {
+            min: *samples.first()?,
+            median: samples[(samples.len() - 1) / 2],
+            max: *samples.last()?,
+        })
+    }
+}
+
+/// Statistics of each stage of a solution, run `iterations` times. Parts that
+/// were not run have no statistics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Benchmark {
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part_one: Option<Stats>,
+    pub part_two: Option<Stats>,
+}
+
+/// Wall time taken by each stage of a single run. Parts that were not run
+/// have no timing.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Option<Duration>,
+    pub part_two: Option<Duration>,
+}
+
+/// Runs `stage` the given number of `iterations`, returning the value of the
+/// last run together with the statistics of every run. The time taken to drop
+/// the values of previous runs is not measured.
+pub fn measure<T>(
+    iterations: usize,
+    mut stage: impl FnMut() -> Result<T, Error>,
+) -> Result<(T, Stats), Error> {
+    let mut samples = Vec::with_capacity(iterations);
+    let mut value = None;
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let result = stage()?;
+        samples.push(start.elapsed());
+        value = Some(result);
+    }
+
+    let stats = Stats::from_samples(&mut samples);
+
+    value
+        .zip(stats)
+        .ok_or_else(|| Error::solve("benchmarks need at least one iteration"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_samples_with_odd_number_of_samples() {
+        let mut samples = [5, 1, 3].map(Duration::from_millis);
+        let result = Stats::from_samples(&mut samples).unwrap();
+        let expected = Stats {
+            min: Duration::from_millis(1),
+            median: Duration::from_millis(3),
+            max: Duration::from_millis(5),
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn from_samples_with_even_number_of_samples() {
+        let mut samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let result = Stats::from_samples(&mut samples).unwrap();
+
+        assert_eq!(result.median, Duration::from_millis(2));
+    }
+
+    #[test]
+    fn from_samples_with_no_samples() {
+        assert!(Stats::from_samples(&mut []).is_none());
+    }
+
+    #[test]
+    fn measure_runs_every_iteration() {
+        let mut runs = 0;
+        let (value, _) = measure(5, || {
+            runs += 1;
+            Ok(runs)
+        })
+        .unwrap();
+
+        assert_eq!(value, 5);
+    }
+
+    #[test]
+    fn measure_with_no_iterations() {
+        assert!(measure(0, || Ok(())).is_err());
+    }
+}
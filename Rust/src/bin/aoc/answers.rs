@@ -0,0 +1,121 @@
+use advent_of_code::Part;
+
+/// Default location of the file with the known answers of the inputs.
+pub const ANSWERS_PATH: &str = "inputs/answers.txt";
+
+/// Known answer of a part of the puzzle of a day for a given input file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub answer: String,
+}
+
+/// Parses the answers file. Each line holds the day, the part, the path of the
+/// input file (or `example` for the example of the puzzle) and the answer,
+/// separated by tabs. Newlines, tabs and backslashes in the answer are escaped
+/// as `\n`, `\t` and `\\`. Blank lines and lines starting with `#` are ignored.
+pub fn parse_answers(content: &str) -> Result<Vec<Expected>, String> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i, line.trim_end_matches('\r')))
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| parse_line(line).map_err(|err| format!("line {}: {}", i + 1, err)))
+        .collect()
+}
+
+fn parse_line(line: &str) -> Result<Expected, String> {
+    let fields: Vec<&str> = line.split('\t').collect();
+    let [day, part, input, answer] = fields[..] else {
+        return Err(format!("expected 4 fields, found {}", fields.len()));
+    };
+
+    let day = day.parse().map_err(|_| format!("invalid day `{}`", day))?;
+    let part = match part {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return Err(format!("invalid part `{}`", part)),
+    };
+
+    Ok(Expected {
+        day,
+        part,
+        input: input.to_string(),
+        answer: unescape(answer)?,
+    })
+}
+
+fn unescape(value: &str) -> Result<String, String> {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('\\') => result.push('\\'),
+            Some(other) => return Err(format!("invalid escape `\\{}`", other)),
+            None => return Err("unfinished escape at the end of the answer".to_string()),
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answers_with_valid_content() {
+        let content = "# day\tpart\tinput\tanswer\n\
+                       \n\
+                       1\t1\tinputs/day01.txt\t24000\n\
+                       10\t2\tinputs/day10.txt\t#.\\n.#\\n\n";
+        let result = parse_answers(content).unwrap();
+        let expected = vec![
+            Expected {
+                day: 1,
+                part: Part::One,
+                input: "inputs/day01.txt".to_string(),
+                answer: "24000".to_string(),
+            },
+            Expected {
+                day: 10,
+                part: Part::Two,
+                input: "inputs/day10.txt".to_string(),
+                answer: "#.\n.#\n".to_string(),
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_answers_with_invalid_content() {
+        let test_cases = [
+            (
+                "1\t1\tinputs/day01.txt",
+                "line 1: expected 4 fields, found 3",
+            ),
+            ("\none\t1\tinputs/day01.txt\t1", "line 2: invalid day `one`"),
+            ("1\t3\tinputs/day01.txt\t1", "line 1: invalid part `3`"),
+            (
+                "1\t1\tinputs/day01.txt\t1\\x",
+                "line 1: invalid escape `\\x`",
+            ),
+        ];
+
+        for (content, expected) in test_cases {
+            let result = parse_answers(content).unwrap_err();
+            assert_eq!(result, expected);
+        }
+    }
+}
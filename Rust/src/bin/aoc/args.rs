@@ -0,0 +1,452 @@
+use advent_of_code::Part;
+
+pub const USAGE: &str =
+    "Usage: aoc run <DAY|all> [--part <1|2>] [--input <PATH> | --example] [--stream]
+               [--bench <N>] [--format <FMT>]
+       aoc verify [DAY|all] [--answers <PATH>]
+       aoc report <DAY> [NAME] [--input <PATH> | --example] [--format <FMT>]
+                  [--animate <MS>]
+
+Commands:
+  run <DAY|all>    Runs the solution of the given day, or of every day
+  verify [DAY|all] Checks the answers of the given day, or of every day (the
+                   default), against the known answers of their inputs
+  report <DAY> [NAME]
+                   Prints the report of the given day with the given name, with
+                   details of its solution, or lists its reports
+
+Options:
+  --part <1|2>     Only runs the given part of the puzzle
+  --input <PATH>   Reads the puzzle input from PATH (`-` for stdin), defaults
+                   to `inputs/dayNN.txt`
+  --example        Runs the example of the puzzle description, with the
+                   parameters of the example
+  --stream         Solves the puzzle while reading the input, without keeping
+                   all of it in memory (days 1, 6, 9 and 10)
+  --bench <N>      Runs parsing and each part N times and reports the
+                   minimum, median and maximum wall time of each of them
+  --format <FMT>   Prints the results as `text` (default), `csv` or `json`
+                   (reports are only printed as `text` or `csv`)
+  --animate <MS>   Plays a report with frames as an animation in the
+                   terminal, showing each frame for MS milliseconds
+  --<NAME> [VALUE] Sets a parameter of the puzzle of the day, such as
+                   `--row 10` for day 15
+  --answers <PATH> Reads the known answers from PATH, defaults to
+                   `inputs/answers.txt`";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Selection {
+    All,
+    Day(u8),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Csv,
+    Json,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunOptions {
+    pub part: Option<Part>,
+    pub input: Option<String>,
+    pub example: bool,
+    pub stream: bool,
+    pub bench: Option<usize>,
+    pub format: Format,
+    /// Milliseconds each frame of a report is shown for, to play it as an
+    /// animation.
+    pub animate: Option<u64>,
+    /// Parameters of the puzzle, with their optional value.
+    pub params: Vec<(String, Option<String>)>,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        RunOptions {
+            part: None,
+            input: None,
+            example: false,
+            stream: false,
+            bench: None,
+            format: Format::Text,
+            animate: None,
+            params: vec![],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        selection: Selection,
+        options: RunOptions,
+    },
+    Verify {
+        selection: Selection,
+        answers: Option<String>,
+    },
+    Report {
+        day: u8,
+        /// Name of the report, or `None` to list the reports of the day.
+        name: Option<String>,
+        options: RunOptions,
+    },
+    Help,
+}
+
+pub fn parse_args(args: &[String]) -> Result<Command, String> {
+    match args.first().map(String::as_str) {
+        Some("run") => parse_run_args(&args[1..]),
+        Some("verify") => parse_verify_args(&args[1..]),
+        Some("report") => parse_report_args(&args[1..]),
+        Some("help" | "-h" | "--help") | None => Ok(Command::Help),
+        Some(other) => Err(format!("Unknown command `{}`", other)),
+    }
+}
+
+fn parse_selection(arg: &str) -> Result<Selection, String> {
+    match arg {
+        "all" => Ok(Selection::All),
+        day => Ok(Selection::Day(
+            day.parse().map_err(|_| format!("Invalid day `{}`", day))?,
+        )),
+    }
+}
+
+fn parse_run_args(args: &[String]) -> Result<Command, String> {
+    let selection = match args.first() {
+        Some(arg) => parse_selection(arg)?,
+        None => return Err("Missing day to run".to_string()),
+    };
+    let options = parse_options(&args[1..])?;
+
+    if selection == Selection::All && options.input.is_some() {
+        return Err("`--input` can only be used when running a single day".to_string());
+    }
+
+    if options.stream && options.bench.is_some() {
+        return Err("`--stream` cannot be used with `--bench`".to_string());
+    }
+
+    if options.animate.is_some() {
+        return Err("`--animate` can only be used with reports".to_string());
+    }
+
+    if selection == Selection::All && !options.params.is_empty() {
+        return Err("Parameters can only be used when running a single day".to_string());
+    }
+
+    Ok(Command::Run { selection, options })
+}
+
+fn parse_report_args(args: &[String]) -> Result<Command, String> {
+    let day = match args.first() {
+        Some(day) => day.parse().map_err(|_| format!("Invalid day `{}`", day))?,
+        None => return Err("Missing day of the report".to_string()),
+    };
+    let name = args.get(1).filter(|arg| !arg.starts_with("--")).cloned();
+    let options = parse_options(&args[1 + usize::from(name.is_some())..])?;
+
+    if options.part.is_some() || options.stream || options.bench.is_some() {
+        return Err("`--part`, `--stream` and `--bench` cannot be used with reports".to_string());
+    }
+
+    if options.format == Format::Json {
+        return Err("Reports can only be printed as `text` or `csv`".to_string());
+    }
+
+    if options.format == Format::Csv && options.animate.is_some() {
+        return Err("`--animate` cannot be used with `--format csv`".to_string());
+    }
+
+    Ok(Command::Report { day, name, options })
+}
+
+/// Parses the options of the commands that solve puzzles.
+fn parse_options(args: &[String]) -> Result<RunOptions, String> {
+    let mut args = args.iter().peekable();
+    let mut options = RunOptions::default();
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for `{}`", arg))
+        };
+
+        match arg.as_str() {
+            "--part" => {
+                let value = value()?;
+                options.part = match value.as_str() {
+                    "1" => Some(Part::One),
+                    "2" => Some(Part::Two),
+                    _ => return Err(format!("Invalid part `{}`", value)),
+                };
+            }
+            "--input" => options.input = Some(value()?.clone()),
+            "--example" => options.example = true,
+            "--stream" => options.stream = true,
+            "--bench" => {
+                let value = value()?;
+                options.bench = match value.parse() {
+                    Ok(iterations) if iterations > 0 => Some(iterations),
+                    _ => return Err(format!("Invalid number of iterations `{}`", value)),
+                };
+            }
+            "--format" => {
+                let value = value()?;
+                options.format = match value.as_str() {
+                    "text" => Format::Text,
+                    "csv" => Format::Csv,
+                    "json" => Format::Json,
+                    _ => return Err(format!("Invalid format `{}`", value)),
+                };
+            }
+            "--animate" => {
+                let value = value()?;
+                options.animate = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("Invalid frame duration `{}`", value))?,
+                );
+            }
+            other => match other.strip_prefix("--") {
+                Some(name) if !name.is_empty() => {
+                    let value = args.next_if(|value| !value.starts_with("--")).cloned();
+                    options.params.push((name.to_string(), value));
+                }
+                _ => return Err(format!("Unknown option `{}`", other)),
+            },
+        }
+    }
+
+    if options.example && options.input.is_some() {
+        return Err("`--example` cannot be used with `--input`".to_string());
+    }
+
+    Ok(options)
+}
+
+fn parse_verify_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter().peekable();
+    let selection = match args.next_if(|arg| !arg.starts_with("--")) {
+        Some(arg) => parse_selection(arg)?,
+        None => Selection::All,
+    };
+    let mut answers = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("Missing value for `{}`", arg))?;
+                answers = Some(value.clone());
+            }
+            other => return Err(format!("Unknown option `{}`", other)),
+        }
+    }
+
+    Ok(Command::Verify { selection, answers })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(input: &str) -> Vec<String> {
+        input.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parse_args_with_single_day() {
+        let result = parse_args(&args("run 12 --part 2 --input inputs/day12.txt")).unwrap();
+        let expected = Command::Run {
+            selection: Selection::Day(12),
+            options: RunOptions {
+                part: Some(Part::Two),
+                input: Some("inputs/day12.txt".to_string()),
+                ..RunOptions::default()
+            },
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_args_with_all_days() {
+        let result = parse_args(&args("run all")).unwrap();
+        let expected = Command::Run {
+            selection: Selection::All,
+            options: RunOptions::default(),
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_args_with_bench() {
+        let result = parse_args(&args("run all --bench 10 --format csv")).unwrap();
+        let expected = Command::Run {
+            selection: Selection::All,
+            options: RunOptions {
+                bench: Some(10),
+                format: Format::Csv,
+                ..RunOptions::default()
+            },
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_args_with_json_format() {
+        let result = parse_args(&args("run 8 --stream --format json")).unwrap();
+        let expected = Command::Run {
+            selection: Selection::Day(8),
+            options: RunOptions {
+                stream: true,
+                format: Format::Json,
+                ..RunOptions::default()
+            },
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_args_with_example() {
+        let result = parse_args(&args("run all --example")).unwrap();
+        let expected = Command::Run {
+            selection: Selection::All,
+            options: RunOptions {
+                example: true,
+                ..RunOptions::default()
+            },
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_args_with_parameters() {
+        let result = parse_args(&args("run 15 --row 10 --part 1 --limit 20 --fast")).unwrap();
+        let expected = Command::Run {
+            selection: Selection::Day(15),
+            options: RunOptions {
+                part: Some(Part::One),
+                params: vec![
+                    ("row".to_string(), Some("10".to_string())),
+                    ("limit".to_string(), Some("20".to_string())),
+                    ("fast".to_string(), None),
+                ],
+                ..RunOptions::default()
+            },
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_args_with_verify() {
+        let test_cases = [
+            ("verify", Selection::All, None),
+            ("verify 7", Selection::Day(7), None),
+            (
+                "verify --answers answers.txt",
+                Selection::All,
+                Some("answers.txt".to_string()),
+            ),
+        ];
+
+        for (args_input, selection, answers) in test_cases {
+            let result = parse_args(&args(args_input)).unwrap();
+            assert_eq!(result, Command::Verify { selection, answers });
+        }
+    }
+
+    #[test]
+    fn parse_args_with_report() {
+        let test_cases = [
+            ("report 1", 1, None, vec![]),
+            ("report 1 ranking", 1, Some("ranking"), vec![]),
+            (
+                "report 1 ranking --top 5",
+                1,
+                Some("ranking"),
+                vec![("top".to_string(), Some("5".to_string()))],
+            ),
+            (
+                "report 1 --top 5",
+                1,
+                None,
+                vec![("top".to_string(), Some("5".to_string()))],
+            ),
+        ];
+
+        for (args_input, day, name, params) in test_cases {
+            let result = parse_args(&args(args_input)).unwrap();
+            let expected = Command::Report {
+                day,
+                name: name.map(String::from),
+                options: RunOptions {
+                    params,
+                    ..RunOptions::default()
+                },
+            };
+
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn parse_args_with_animated_report() {
+        let result = parse_args(&args("report 5 frames --animate 200")).unwrap();
+        let expected = Command::Report {
+            day: 5,
+            name: Some("frames".to_string()),
+            options: RunOptions {
+                animate: Some(200),
+                ..RunOptions::default()
+            },
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_args_with_invalid_arguments() {
+        let test_cases = [
+            "run",
+            "run twelve",
+            "run 12 --part 3",
+            "run 12 --input",
+            "run 12 -verbose",
+            "run 12 --",
+            "run all --row 10",
+            "run 6 --stream --bench 10",
+            "run 6 --example --input inputs/day06.txt",
+            "run 12 --bench 0",
+            "run 12 --bench ten",
+            "run 12 --format xml",
+            "run all --input inputs/day12.txt",
+            "walk 12",
+            "verify seven",
+            "verify 7 --answers",
+            "verify 7 --part 1",
+            "report",
+            "report all ranking",
+            "report 1 ranking --part 1",
+            "report 1 ranking --format json",
+            "report 5 frames --animate fast",
+            "report 5 frames --animate 200 --format csv",
+            "run 5 --animate 200",
+        ];
+
+        for t in test_cases {
+            let result = parse_args(&args(t));
+            assert!(result.is_err(), "expected an error for {:?}", t);
+        }
+    }
+}
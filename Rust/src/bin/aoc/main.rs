@@ -0,0 +1,206 @@
+use std::{
+    env,
+    fs::{self, File},
+    io::{stdin, BufRead, BufReader},
+    process::exit,
+    time::Duration,
+};
+
+use advent_of_code::{
+    days::{find_puzzle, puzzles},
+    read_input, Error, ErrorKind, Puzzle,
+};
+
+use answers::{parse_answers, ANSWERS_PATH};
+use args::{parse_args, Command, RunOptions, Selection, USAGE};
+use verify::verify;
+
+mod answers;
+mod args;
+mod output;
+mod verify;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = parse_args(&args).unwrap_or_else(|err| {
+        eprintln!("{}\n\n{}", err, USAGE);
+        exit(2);
+    });
+
+    match command {
+        Command::Help => println!("{}", USAGE),
+        Command::Run { selection, options } => {
+            let puzzles = match selection {
+                Selection::All => puzzles(),
+                Selection::Day(day) => vec![find_puzzle(day).unwrap_or_else(|| {
+                    eprintln!("There is no solution for day {}", day);
+                    exit(2);
+                })],
+            };
+            let puzzles: Vec<Box<dyn Puzzle>> = puzzles
+                .into_iter()
+                .map(|mut puzzle| {
+                    if let Err(err) = configure(puzzle.as_mut(), &options) {
+                        eprintln!("Error: {}", err);
+                        exit(2);
+                    }
+                    puzzle
+                })
+                .collect();
+
+            output::print_header(options.format, options.bench.is_some());
+
+            let mut failed = false;
+            for puzzle in puzzles {
+                if !run_puzzle(puzzle.as_ref(), &options) {
+                    failed = true;
+                }
+            }
+
+            if failed {
+                exit(1);
+            }
+        }
+        Command::Verify { selection, answers } => {
+            let path = answers.as_deref().unwrap_or(ANSWERS_PATH);
+            let expected = fs::read_to_string(path)
+                .map_err(|err| format!("Could not read answers from `{}`: {}", path, err))
+                .and_then(|content| {
+                    parse_answers(&content)
+                        .map_err(|err| format!("Invalid answers in `{}`: {}", path, err))
+                })
+                .unwrap_or_else(|err| {
+                    eprintln!("{}", err);
+                    exit(2);
+                });
+
+            if !verify(&expected, &selection) {
+                exit(1);
+            }
+        }
+        Command::Report { day, name, options } => {
+            let mut puzzle = find_puzzle(day).unwrap_or_else(|| {
+                eprintln!("There is no solution for day {}", day);
+                exit(2);
+            });
+            if let Err(err) = configure(puzzle.as_mut(), &options) {
+                eprintln!("Error: {}", err);
+                exit(2);
+            }
+
+            let Some(name) = name else {
+                output::print_reports(puzzle.as_ref());
+                return;
+            };
+
+            let report = open_options_input(puzzle.as_ref(), &options)
+                .and_then(|mut reader| {
+                    read_input(&mut reader).map_err(|err| err.with_day(puzzle.day()))
+                })
+                .and_then(|input| puzzle.report(&name, &input));
+
+            match report {
+                Ok(report) => match options.animate {
+                    Some(delay) => output::animate_report(&report, Duration::from_millis(delay)),
+                    None => output::print_report(&report, options.format),
+                },
+                Err(err) => {
+                    output::print_error(day, &err, options.format);
+                    exit(1);
+                }
+            }
+        }
+    }
+}
+
+/// Sets the parameters of the `puzzle` given in the `options`, after the ones
+/// of its example when running it.
+fn configure(puzzle: &mut dyn Puzzle, options: &RunOptions) -> Result<(), Error> {
+    if options.example {
+        puzzle.configure_example()?;
+    }
+
+    for (name, value) in &options.params {
+        puzzle.configure(name, value.as_deref())?;
+    }
+
+    Ok(())
+}
+
+/// Runs the requested parts of the given `puzzle`, or benchmarks them, and
+/// prints the results. Returns `false` if the input could not be read or any
+/// of the parts failed.
+fn run_puzzle(puzzle: &dyn Puzzle, options: &RunOptions) -> bool {
+    output::print_title(puzzle, options.format);
+
+    let reader = || open_options_input(puzzle, options);
+
+    let result = if options.stream {
+        reader()
+            .and_then(|mut reader| puzzle.solve_reader(&mut reader, options.part))
+            .map(|answers| output::print_answers(puzzle.day(), &answers, options.format))
+    } else {
+        let input = reader().and_then(|mut reader| {
+            read_input(&mut reader).map_err(|err| err.with_day(puzzle.day()))
+        });
+
+        input.and_then(|input| match options.bench {
+            Some(iterations) => {
+                puzzle
+                    .bench(&input, options.part, iterations)
+                    .map(|(answers, benchmark)| {
+                        output::print_benchmark(puzzle.day(), &answers, &benchmark, options.format)
+                    })
+            }
+            None => puzzle
+                .solve(&input, options.part)
+                .map(|answers| output::print_answers(puzzle.day(), &answers, options.format)),
+        })
+    };
+
+    match result {
+        Ok(()) => true,
+        Err(err) => {
+            output::print_error(puzzle.day(), &err, options.format);
+            false
+        }
+    }
+}
+
+/// Opens the input of the `puzzle` chosen in the `options`: its example, or
+/// the one given by [`open_input`].
+fn open_options_input(
+    puzzle: &dyn Puzzle,
+    options: &RunOptions,
+) -> Result<Box<dyn BufRead>, Error> {
+    if options.example {
+        Ok(Box::new(puzzle.example().as_bytes()))
+    } else {
+        open_input(puzzle, options.input.as_deref())
+    }
+}
+
+/// Reads the whole input of the `puzzle`, see [`open_input`].
+fn load_input(puzzle: &dyn Puzzle, input_path: Option<&str>) -> Result<String, Error> {
+    open_input(puzzle, input_path)
+        .and_then(|mut reader| read_input(&mut reader).map_err(|err| err.with_day(puzzle.day())))
+}
+
+/// Opens the input of the `puzzle` from `input_path`, from stdin when it is
+/// `-`, or from `inputs/dayNN.txt` when no path is given.
+fn open_input(puzzle: &dyn Puzzle, input_path: Option<&str>) -> Result<Box<dyn BufRead>, Error> {
+    let default_path = format!("inputs/day{:02}.txt", puzzle.day());
+    let reader: Box<dyn BufRead> = match input_path.unwrap_or(&default_path) {
+        "-" => Box::new(stdin().lock()),
+        path => Box::new(BufReader::new(File::open(path).map_err(|err| {
+            Error::new(
+                ErrorKind::Io,
+                format!("could not read input from `{}`", path),
+            )
+            .with_source(err)
+            .with_day(puzzle.day())
+        })?)),
+    };
+
+    Ok(reader)
+}
@@ -0,0 +1,301 @@
+use std::{
+    error::Error as _,
+    fmt::Write,
+    io::{self, Write as _},
+    thread,
+    time::Duration,
+};
+
+use advent_of_code::{
+    bench::{Benchmark, Stats},
+    report::Report,
+    Answers, Error, Puzzle,
+};
+
+use crate::args::Format;
+
+/// Prints whatever goes before the results of every puzzle.
+pub fn print_header(format: Format, bench: bool) {
+    match (format, bench) {
+        (Format::Csv, true) => println!("day,stage,iterations,min_ns,median_ns,max_ns"),
+        (Format::Csv, false) => println!("day,part,answer"),
+        (Format::Text | Format::Json, _) => {}
+    }
+}
+
+/// Prints the title of the `puzzle` about to be run.
+pub fn print_title(puzzle: &dyn Puzzle, format: Format) {
+    if format == Format::Text {
+        println!("Day {}: {}", puzzle.day(), puzzle.title());
+    }
+}
+
+pub fn print_answers(day: u8, answers: &Answers, format: Format) {
+    match format {
+        Format::Text | Format::Csv => {
+            let parts = [(1, &answers.part_one), (2, &answers.part_two)];
+
+            for (part, answer) in parts {
+                let Some(answer) = answer else { continue };
+
+                match format {
+                    Format::Csv => println!("{},{},{}", day, part, csv_field(answer)),
+                    _ => print_answer(part, answer),
+                }
+            }
+        }
+        Format::Json => {
+            let timings = &answers.timings;
+            let mut json = String::from("{");
+
+            write!(json, "\"parse_ns\": {}", timings.parse.as_nanos()).unwrap();
+            for (part, timing) in [(1, timings.part_one), (2, timings.part_two)] {
+                if let Some(timing) = timing {
+                    write!(json, ", \"part{}_ns\": {}", part, timing.as_nanos()).unwrap();
+                }
+            }
+            json.push('}');
+
+            println!("{}", json_result(day, answers, &json));
+        }
+    }
+}
+
+fn print_answer(part: u8, answer: &str) {
+    if answer.contains('\n') {
+        println!("  Part {}:", part);
+        for line in answer.lines() {
+            println!("    {}", line);
+        }
+    } else {
+        println!("  Part {}: {}", part, answer);
+    }
+}
+
+pub fn print_benchmark(day: u8, answers: &Answers, benchmark: &Benchmark, format: Format) {
+    let stages = [
+        ("Parse", "parse", Some(benchmark.parse)),
+        ("Part 1", "part1", benchmark.part_one),
+        ("Part 2", "part2", benchmark.part_two),
+    ];
+
+    if format == Format::Json {
+        let mut json = format!("{{\"iterations\": {}", benchmark.iterations);
+
+        for (_, stage, stats) in stages {
+            if let Some(stats) = stats {
+                write!(
+                    json,
+                    ", \"{}\": {{\"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                    stage,
+                    stats.min.as_nanos(),
+                    stats.median.as_nanos(),
+                    stats.max.as_nanos()
+                )
+                .unwrap();
+            }
+        }
+        json.push('}');
+
+        println!("{}", json_result(day, answers, &json));
+        return;
+    }
+
+    for (label, stage, stats) in stages {
+        let Some(stats) = stats else { continue };
+
+        match format {
+            Format::Csv => println!(
+                "{},{},{},{}",
+                day,
+                stage,
+                benchmark.iterations,
+                csv_stats(&stats)
+            ),
+            _ => println!(
+                "  {:<7} min {:>10.2?}  median {:>10.2?}  max {:>10.2?}  ({} runs)",
+                format!("{}:", label),
+                stats.min,
+                stats.median,
+                stats.max,
+                benchmark.iterations
+            ),
+        }
+    }
+}
+
+/// Lists the names of the reports of the `puzzle`.
+pub fn print_reports(puzzle: &dyn Puzzle) {
+    match puzzle.reports() {
+        [] => println!("Day {} has no reports", puzzle.day()),
+        reports => {
+            println!("Reports of day {}:", puzzle.day());
+            for name in reports {
+                println!("  {}", name);
+            }
+        }
+    }
+}
+
+/// Prints the `report`. Only tables can be printed as CSV, any other report is
+/// printed as it is.
+pub fn print_report(report: &Report, format: Format) {
+    let text = match (report, format) {
+        (Report::Table(table), Format::Csv) => {
+            let mut csv = String::new();
+
+            for row in [table.headers()]
+                .into_iter()
+                .chain(table.rows().iter().map(Vec::as_slice))
+            {
+                let fields: Vec<String> = row.iter().map(|value| csv_field(value)).collect();
+                writeln!(csv, "{}", fields.join(",")).unwrap();
+            }
+
+            csv
+        }
+        (report, _) => report.to_string(),
+    };
+
+    print!("{}", text);
+    if !text.ends_with('\n') {
+        println!();
+    }
+}
+
+/// Plays a report with frames as an animation, drawing each frame over the
+/// previous one after `delay`. Any other report is printed as it is.
+pub fn animate_report(report: &Report, delay: Duration) {
+    let Report::Frames(frames) = report else {
+        print_report(report, Format::Text);
+        return;
+    };
+
+    for (index, frame) in frames.iter().enumerate() {
+        if index > 0 {
+            thread::sleep(delay);
+        }
+
+        // Clears the screen and moves the cursor to its top left corner.
+        print!("\x1b[2J\x1b[H{}", frame);
+        io::stdout().flush().unwrap();
+    }
+}
+
+/// Prints the `err` followed by the chain of errors that caused it. With the
+/// JSON format the error is also printed to stdout, so every day run has a
+/// result there.
+pub fn print_error(day: u8, err: &Error, format: Format) {
+    eprintln!("Error: {}", err);
+
+    let mut source = err.source();
+    while let Some(cause) = source {
+        eprintln!("  caused by: {}", cause);
+        source = cause.source();
+    }
+
+    if format == Format::Json {
+        println!(
+            "{{\"day\": {}, \"error\": {}}}",
+            day,
+            json_string(&err.to_string())
+        );
+    }
+}
+
+fn csv_stats(stats: &Stats) -> String {
+    [stats.min, stats.median, stats.max]
+        .map(|duration: Duration| duration.as_nanos().to_string())
+        .join(",")
+}
+
+/// Quotes the `value` if it holds characters with a special meaning in CSV.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Object with the answers of a day, where `timings` is already JSON.
+fn json_result(day: u8, answers: &Answers, timings: &str) -> String {
+    format!(
+        "{{\"day\": {}, \"part1\": {}, \"part2\": {}, \"timings\": {}}}",
+        day,
+        json_answer(answers.part_one.as_deref()),
+        json_answer(answers.part_two.as_deref()),
+        timings
+    )
+}
+
+/// Integer answers are written as JSON numbers and every other answer as a
+/// string. Parts that were not run are `null`.
+fn json_answer(answer: Option<&str>) -> String {
+    match answer {
+        Some(answer) if answer.parse::<i64>().map(|n| n.to_string()).as_deref() == Ok(answer) => {
+            answer.to_string()
+        }
+        Some(answer) => json_string(answer),
+        None => "null".to_string(),
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::from("\"");
+
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_field_with_special_characters() {
+        assert_eq!(csv_field("1306611"), "1306611");
+        assert_eq!(csv_field("a,\"b\"\nc"), "\"a,\"\"b\"\"\nc\"");
+    }
+
+    #[test]
+    fn json_answer_with_numbers_and_strings() {
+        assert_eq!(json_answer(Some("1798")), "1798");
+        assert_eq!(json_answer(Some("-3")), "-3");
+        assert_eq!(json_answer(Some("007")), "\"007\"");
+        assert_eq!(json_answer(Some("SHMSDGZVC")), "\"SHMSDGZVC\"");
+        assert_eq!(json_answer(None), "null");
+    }
+
+    #[test]
+    fn json_string_with_special_characters() {
+        let result = json_string("# \"a\"\n\\\u{1}");
+
+        assert_eq!(result, "\"# \\\"a\\\"\\n\\\\\\u0001\"");
+    }
+
+    #[test]
+    fn json_result_with_both_parts() {
+        let answers = Answers {
+            part_one: Some("1798".to_string()),
+            part_two: Some("259308".to_string()),
+            ..Answers::default()
+        };
+        let result = json_result(8, &answers, "{}");
+        let expected = "{\"day\": 8, \"part1\": 1798, \"part2\": 259308, \"timings\": {}}";
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,186 @@
+use advent_of_code::{days::find_puzzle, Answers, Error, Part};
+
+use crate::{
+    answers::Expected,
+    args::{Format, Selection},
+    load_input,
+    output::print_error,
+};
+
+/// Input name of the answers of the example of a puzzle.
+const EXAMPLE_INPUT: &str = "example";
+
+/// Runs every day with recorded answers against its inputs and reports which
+/// answers still match. Returns `false` if any answer does not match or could
+/// not be computed.
+pub fn verify(expected: &[Expected], selection: &Selection) -> bool {
+    let expected: Vec<&Expected> = expected
+        .iter()
+        .filter(|expected| match selection {
+            Selection::All => true,
+            Selection::Day(day) => expected.day == *day,
+        })
+        .collect();
+
+    if let (Selection::Day(day), true) = (selection, expected.is_empty()) {
+        eprintln!("There are no recorded answers for day {}", day);
+        return false;
+    }
+
+    let mut passed = 0;
+    let mut failed = 0;
+
+    for group in group_by_input(&expected) {
+        let (day, input) = (group[0].day, group[0].input.as_str());
+        println!("Day {} ({})", day, input);
+
+        match solve(day, input, &group) {
+            Ok(answers) => {
+                for expected in group {
+                    let actual = match expected.part {
+                        Part::One => &answers.part_one,
+                        Part::Two => &answers.part_two,
+                    };
+
+                    if actual.as_deref() == Some(expected.answer.as_str()) {
+                        println!("  Part {}: ok", part_number(expected.part));
+                        passed += 1;
+                    } else {
+                        println!("  Part {}: FAIL", part_number(expected.part));
+                        for line in diff(&expected.answer, actual.as_deref().unwrap_or("")) {
+                            println!("    {}", line);
+                        }
+                        failed += 1;
+                    }
+                }
+            }
+            Err(err) => {
+                print_error(day, &err, Format::Text);
+                failed += group.len();
+            }
+        }
+    }
+
+    println!("\n{} passed, {} failed", passed, failed);
+
+    failed == 0
+}
+
+/// Groups the answers by day and input file, keeping the order of the file.
+fn group_by_input<'a>(expected: &[&'a Expected]) -> Vec<Vec<&'a Expected>> {
+    let mut groups: Vec<Vec<&Expected>> = vec![];
+
+    for expected in expected {
+        let group = groups
+            .iter_mut()
+            .find(|group| group[0].day == expected.day && group[0].input == expected.input);
+
+        match group {
+            Some(group) => group.push(expected),
+            None => groups.push(vec![expected]),
+        }
+    }
+
+    groups
+}
+
+/// Solves the parts of the given day that have recorded answers. The input
+/// `example` stands for the example of the puzzle, solved with its parameters.
+fn solve(day: u8, input_path: &str, expected: &[&Expected]) -> Result<Answers, Error> {
+    let mut puzzle = find_puzzle(day)
+        .ok_or_else(|| Error::solve(format!("there is no solution for day {}", day)))?;
+    let input = match input_path {
+        EXAMPLE_INPUT => {
+            puzzle.configure_example()?;
+            puzzle.example().to_string()
+        }
+        path => load_input(puzzle.as_ref(), Some(path))?,
+    };
+    let part = match (
+        expected.iter().any(|expected| expected.part == Part::One),
+        expected.iter().any(|expected| expected.part == Part::Two),
+    ) {
+        (true, false) => Some(Part::One),
+        (false, true) => Some(Part::Two),
+        _ => None,
+    };
+
+    puzzle.solve(&input, part)
+}
+
+fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+/// Lines describing how the `actual` answer differs from the `expected` one.
+/// Multi-line answers only show the lines that differ.
+fn diff(expected: &str, actual: &str) -> Vec<String> {
+    if !expected.contains('\n') && !actual.contains('\n') {
+        return vec![format!("expected `{}`, got `{}`", expected, actual)];
+    }
+
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut lines = vec![];
+
+    for i in 0..expected.len().max(actual.len()) {
+        let (expected, actual) = (expected.get(i), actual.get(i));
+
+        if expected != actual {
+            lines.push(format!("line {}:", i + 1));
+            if let Some(expected) = expected {
+                lines.push(format!("- {}", expected));
+            }
+            if let Some(actual) = actual {
+                lines.push(format!("+ {}", actual));
+            }
+        }
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expected(day: u8, part: Part, input: &str) -> Expected {
+        Expected {
+            day,
+            part,
+            input: input.to_string(),
+            answer: String::new(),
+        }
+    }
+
+    #[test]
+    fn group_by_input_keeps_order_of_file() {
+        let values = [
+            expected(10, Part::One, "inputs/day10.txt"),
+            expected(10, Part::One, "inputs/day10_example.txt"),
+            expected(10, Part::Two, "inputs/day10.txt"),
+        ];
+        let values: Vec<&Expected> = values.iter().collect();
+        let result = group_by_input(&values);
+
+        assert_eq!(result, vec![vec![values[0], values[2]], vec![values[1]]]);
+    }
+
+    #[test]
+    fn diff_with_single_line_answers() {
+        let result = diff("157", "158");
+
+        assert_eq!(result, vec!["expected `157`, got `158`"]);
+    }
+
+    #[test]
+    fn diff_with_multi_line_answers() {
+        let result = diff("##\n..\n##\n", "##\n.#\n");
+        let expected = vec!["line 2:", "- ..", "+ .#", "line 3:", "- ##"];
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,83 @@
+//! Helpers to set the parameters of a puzzle from their name and value.
+
+use std::str::FromStr;
+
+use crate::{Error, ErrorKind};
+
+/// Parses the `value` given to the parameter with the given `name`.
+pub fn parse_value<T>(name: &str, value: Option<&str>) -> Result<T, Error>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let value = value.ok_or_else(|| {
+        Error::new(
+            ErrorKind::Config,
+            format!("missing value for parameter `{}`", name),
+        )
+    })?;
+
+    value.parse().map_err(|err| {
+        Error::new(
+            ErrorKind::Config,
+            format!("invalid value `{}` for parameter `{}`", value, name),
+        )
+        .with_source(err)
+    })
+}
+
+/// Error for a parameter `name` that is not one of the `known` parameters.
+pub fn unknown_parameter(name: &str, known: &[&str]) -> Error {
+    let message = match known {
+        [] => format!(
+            "unknown parameter `{}`, this puzzle has no parameters",
+            name
+        ),
+        known => format!(
+            "unknown parameter `{}`, expected one of {}",
+            name,
+            known
+                .iter()
+                .map(|name| format!("`{}`", name))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+    };
+
+    Error::new(ErrorKind::Config, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_value_with_valid_value() {
+        let result: u32 = parse_value("row", Some("10")).unwrap();
+
+        assert_eq!(result, 10);
+    }
+
+    #[test]
+    fn parse_value_with_invalid_values() {
+        let result = parse_value::<u32>("row", Some("ten")).unwrap_err();
+        assert_eq!(
+            result.to_string(),
+            "invalid value `ten` for parameter `row`"
+        );
+
+        let result = parse_value::<u32>("row", None).unwrap_err();
+        assert_eq!(result.to_string(), "missing value for parameter `row`");
+    }
+
+    #[test]
+    fn unknown_parameter_lists_known_parameters() {
+        let result = unknown_parameter("rows", &["row", "limit"]);
+
+        assert_eq!(result.kind(), ErrorKind::Config);
+        assert_eq!(
+            result.to_string(),
+            "unknown parameter `rows`, expected one of `row`, `limit`"
+        );
+    }
+}
@@ -0,0 +1,772 @@
+use std::{
+    cmp::Reverse,
+    fmt::Write,
+    io::BufRead,
+    num::{NonZeroUsize, ParseIntError},
+};
+
+use crate::{
+    config::{parse_value, unknown_parameter},
+    input::lines,
+    report::{unknown_report, Report, Table},
+    Error, Part, Solution,
+};
+
+#[derive(Debug, PartialEq)]
+pub struct Elf {
+    inventory: Vec<u32>,
+}
+
+impl Elf {
+    pub fn total_calories(&self) -> u32 {
+        self.inventory.iter().sum()
+    }
+
+    /// Number of food items carried by the Elf.
+    pub fn item_count(&self) -> usize {
+        self.inventory.len()
+    }
+}
+
+#[derive(Debug)]
+pub struct Day01 {
+    /// Number of Elves whose calories are added up in part two.
+    pub top: usize,
+    /// Number of bars of the histogram of the calorie totals.
+    pub histogram_bins: NonZeroUsize,
+    /// Skips items whose calories are not a valid number instead of failing.
+    /// Elves whose every item was skipped carry no calories.
+    pub lenient: bool,
+}
+
+/// Length of the longest bar of the histogram.
+const HISTOGRAM_WIDTH: usize = 50;
+
+impl Default for Day01 {
+    fn default() -> Self {
+        Day01 {
+            top: 3,
+            histogram_bins: NonZeroUsize::new(10).unwrap(),
+            lenient: false,
+        }
+    }
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+    const EXAMPLE: &'static str = include_str!("../../inputs/day01_example.txt");
+    const REPORTS: &'static [&'static str] = &["ranking", "stats", "elves", "histogram"];
+
+    type Input = Vec<Elf>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        process_input(input, self.lenient)
+    }
+
+    fn part_one(&self, elves: &Self::Input) -> Result<Self::PartOne, Error> {
+        Ok(total_calories(&top_elves(elves, 1)?))
+    }
+
+    fn part_two(&self, elves: &Self::Input) -> Result<Self::PartTwo, Error> {
+        Ok(total_calories(&top_elves(elves, self.top)?))
+    }
+
+    /// Only keeps the largest totals while reading the inventories.
+    fn stream(
+        &self,
+        reader: &mut dyn BufRead,
+        part: Option<Part>,
+    ) -> Result<(Option<Self::PartOne>, Option<Self::PartTwo>), Error> {
+        let mut top_totals = TopTotals::new(self.top.max(1));
+        let mut total = None;
+        let mut invalid = InvalidValues::default();
+
+        for line in lines(reader) {
+            let (line_number, line) = line?;
+
+            if line.trim().is_empty() {
+                if let Some(total) = total.take() {
+                    top_totals.push(total);
+                }
+                continue;
+            }
+
+            let total = total.get_or_insert(0);
+            match parse_calories(&line) {
+                Ok(calories) => *total += calories,
+                Err(err) => invalid.push(line_number, top_totals.count + 1, &line, err),
+            }
+        }
+
+        if let Some(total) = total {
+            top_totals.push(total);
+        }
+
+        invalid.check(self.lenient)?;
+
+        let part_one = match part {
+            Some(Part::Two) => None,
+            _ => Some(top_totals.sum(1)?),
+        };
+        let part_two = match part {
+            Some(Part::One) => None,
+            _ => Some(top_totals.sum(self.top)?),
+        };
+
+        Ok((part_one, part_two))
+    }
+
+    fn configure(&mut self, name: &str, value: Option<&str>) -> Result<(), Error> {
+        match name {
+            "top" => self.top = parse_value(name, value)?,
+            "bins" => self.histogram_bins = parse_value(name, value)?,
+            "lenient" => self.lenient = value.is_none() || parse_value(name, value)?,
+            _ => return Err(unknown_parameter(name, &["top", "bins", "lenient"])),
+        }
+
+        Ok(())
+    }
+
+    fn report(&self, name: &str, elves: &Self::Input) -> Result<Report, Error> {
+        match name {
+            "ranking" => {
+                let mut table = Table::new(&["rank", "elf", "calories"]);
+
+                for elf in top_elves(elves, self.top)? {
+                    table.push(vec![
+                        elf.rank.to_string(),
+                        (elf.index + 1).to_string(),
+                        elf.total.to_string(),
+                    ]);
+                }
+
+                Ok(Report::Table(table))
+            }
+            "stats" => {
+                let stats = CalorieStats::new(elves)?;
+                let items: usize = elves.iter().map(Elf::item_count).sum();
+                let mut table = Table::new(&["statistic", "value"]);
+                let rows = [
+                    ("elves", stats.count().to_string()),
+                    ("items", items.to_string()),
+                    (
+                        "mean items",
+                        format!("{:.2}", items as f64 / stats.count() as f64),
+                    ),
+                    ("min", stats.min().to_string()),
+                    ("p10", format!("{:.2}", stats.percentile(10.0))),
+                    ("p25", format!("{:.2}", stats.percentile(25.0))),
+                    ("median", format!("{:.2}", stats.median())),
+                    ("p75", format!("{:.2}", stats.percentile(75.0))),
+                    ("p90", format!("{:.2}", stats.percentile(90.0))),
+                    ("max", stats.max().to_string()),
+                    ("mean", format!("{:.2}", stats.mean())),
+                    ("std dev", format!("{:.2}", stats.std_dev())),
+                ];
+
+                for (statistic, value) in rows {
+                    table.push(vec![statistic.to_string(), value]);
+                }
+
+                Ok(Report::Table(table))
+            }
+            "elves" => {
+                let mut table = Table::new(&["elf", "items", "calories"]);
+
+                for (i, elf) in elves.iter().enumerate() {
+                    table.push(vec![
+                        (i + 1).to_string(),
+                        elf.item_count().to_string(),
+                        elf.total_calories().to_string(),
+                    ]);
+                }
+
+                Ok(Report::Table(table))
+            }
+            "histogram" => {
+                let stats = CalorieStats::new(elves)?;
+
+                Ok(Report::Text(
+                    stats.histogram(self.histogram_bins.get(), HISTOGRAM_WIDTH),
+                ))
+            }
+            _ => Err(unknown_report(name, Self::REPORTS)),
+        }
+    }
+}
+
+/// Place of an Elf among the ones carrying the most calories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RankedElf {
+    /// Position of the Elf in the input, starting at 0.
+    pub index: usize,
+    /// Calories carried by the Elf.
+    pub total: u32,
+    /// Rank of the Elf, starting at 1. Elves carrying the same calories share
+    /// the same rank, and the next rank skips the ones they took.
+    pub rank: usize,
+}
+
+/// The `n` Elves carrying the most calories, from most to least. Elves with the
+/// same total are ordered by their position in the input, so only the first of
+/// them are kept when they do not all fit in `n`.
+pub fn top_elves(elves: &[Elf], n: usize) -> Result<Vec<RankedElf>, Error> {
+    if elves.len() < n {
+        return Err(not_enough_elves(n, elves.len()));
+    }
+
+    let mut ranked: Vec<RankedElf> = elves
+        .iter()
+        .enumerate()
+        .map(|(index, elf)| RankedElf {
+            index,
+            total: elf.total_calories(),
+            rank: 0,
+        })
+        .collect();
+    ranked.sort_by_key(|elf| (Reverse(elf.total), elf.index));
+    ranked.truncate(n);
+
+    for i in 0..ranked.len() {
+        ranked[i].rank = match i {
+            0 => 1,
+            i if ranked[i].total == ranked[i - 1].total => ranked[i - 1].rank,
+            i => i + 1,
+        };
+    }
+
+    Ok(ranked)
+}
+
+fn total_calories(elves: &[RankedElf]) -> u32 {
+    elves.iter().map(|elf| elf.total).sum()
+}
+
+fn not_enough_elves(expected: usize, found: usize) -> Error {
+    Error::solve(format!(
+        "not enough Elves, expected at least {} but found {}",
+        expected, found
+    ))
+}
+
+/// Statistics of the calories carried by each Elf.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalorieStats {
+    /// Calories carried by each Elf, from least to most.
+    totals: Vec<u32>,
+}
+
+impl CalorieStats {
+    pub fn new(elves: &[Elf]) -> Result<CalorieStats, Error> {
+        if elves.is_empty() {
+            return Err(Error::solve("could not find any Elves"));
+        }
+
+        let mut totals: Vec<u32> = elves.iter().map(Elf::total_calories).collect();
+        totals.sort_unstable();
+
+        Ok(CalorieStats { totals })
+    }
+
+    /// Number of Elves.
+    pub fn count(&self) -> usize {
+        self.totals.len()
+    }
+
+    pub fn min(&self) -> u32 {
+        self.totals[0]
+    }
+
+    pub fn max(&self) -> u32 {
+        self.totals[self.totals.len() - 1]
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.totals
+            .iter()
+            .map(|&total| f64::from(total))
+            .sum::<f64>()
+            / self.count() as f64
+    }
+
+    pub fn median(&self) -> f64 {
+        self.percentile(50.0)
+    }
+
+    /// Calories below which the given `percentile` of the Elves fall, from 0
+    /// to 100, interpolating linearly between the two closest Elves.
+    pub fn percentile(&self, percentile: f64) -> f64 {
+        let rank = percentile.clamp(0.0, 100.0) / 100.0 * (self.count() - 1) as f64;
+        let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+        let (lower_total, upper_total) =
+            (f64::from(self.totals[lower]), f64::from(self.totals[upper]));
+
+        lower_total + (upper_total - lower_total) * (rank - lower as f64)
+    }
+
+    /// Population standard deviation of the calories.
+    pub fn std_dev(&self) -> f64 {
+        let mean = self.mean();
+        let variance = self
+            .totals
+            .iter()
+            .map(|&total| (f64::from(total) - mean).powi(2))
+            .sum::<f64>()
+            / self.count() as f64;
+
+        variance.sqrt()
+    }
+
+    /// Number of Elves in each of `bins` ranges of calories of the same size,
+    /// from the least to the most calories, along with the first and last
+    /// calories of each range.
+    pub fn bins(&self, bins: usize) -> Vec<(u32, u32, usize)> {
+        let (min, max) = (u64::from(self.min()), u64::from(self.max()));
+        let size = (max - min + 1).div_ceil(bins.max(1) as u64);
+        let mut counts = vec![0; (max - min + 1).div_ceil(size) as usize];
+
+        for &total in &self.totals {
+            counts[((u64::from(total) - min) / size) as usize] += 1;
+        }
+
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| {
+                let start = min + i as u64 * size;
+                let end = (start + size - 1).min(max);
+
+                (start as u32, end as u32, count)
+            })
+            .collect()
+    }
+
+    /// Draws the [`CalorieStats::bins`] as horizontal bars, where the longest
+    /// bar is `width` characters long.
+    pub fn histogram(&self, bins: usize, width: usize) -> String {
+        let bins = self.bins(bins);
+        let label_width = self.max().to_string().len();
+        let most = bins.iter().map(|&(_, _, count)| count).max().unwrap_or(0);
+        let mut histogram = String::new();
+
+        for (start, end, count) in bins {
+            let length = (count * width).div_ceil(most.max(1));
+            let bar = match length {
+                0 => count.to_string(),
+                length => format!("{} {}", "#".repeat(length), count),
+            };
+
+            writeln!(
+                histogram,
+                "{:>label_width$} - {:>label_width$} | {}",
+                start, end, bar
+            )
+            .unwrap();
+        }
+
+        histogram
+    }
+}
+
+/// Largest calorie totals seen so far, from largest to smallest.
+#[derive(Debug)]
+struct TopTotals {
+    totals: Vec<u32>,
+    capacity: usize,
+    count: usize,
+}
+
+impl TopTotals {
+    /// Keeps the `capacity` largest totals.
+    fn new(capacity: usize) -> TopTotals {
+        TopTotals {
+            totals: Vec::with_capacity(capacity + 1),
+            capacity,
+            count: 0,
+        }
+    }
+
+    fn push(&mut self, total: u32) {
+        let i = self.totals.partition_point(|&top| top >= total);
+        if i < self.capacity {
+            self.totals.insert(i, total);
+            self.totals.truncate(self.capacity);
+        }
+        self.count += 1;
+    }
+
+    /// Sum of the `n` largest totals, up to the capacity, if there were at
+    /// least `n` of them.
+    fn sum(&self, n: usize) -> Result<u32, Error> {
+        if self.count < n {
+            return Err(not_enough_elves(n, self.count));
+        }
+
+        Ok(self.totals.iter().take(n).sum())
+    }
+}
+
+/// Parses the inventory of each Elf. Inventories are separated by blank lines
+/// and each line holds the calories of a single item. In `lenient` mode items
+/// with invalid calories are skipped, otherwise every one of them is reported.
+fn process_input(input: &str, lenient: bool) -> Result<Vec<Elf>, Error> {
+    let mut elves = vec![];
+    let mut inventory: Option<Vec<u32>> = None;
+    let mut invalid = InvalidValues::default();
+
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            if let Some(inventory) = inventory.take() {
+                elves.push(Elf { inventory });
+            }
+            continue;
+        }
+
+        let items = inventory.get_or_insert_with(Vec::new);
+        match parse_calories(line) {
+            Ok(calories) => items.push(calories),
+            Err(err) => invalid.push(i + 1, elves.len() + 1, line, err),
+        }
+    }
+
+    if let Some(inventory) = inventory {
+        elves.push(Elf { inventory });
+    }
+
+    invalid.check(lenient)?;
+
+    Ok(elves)
+}
+
+/// Calories of a single item, ignoring the whitespace around them.
+fn parse_calories(line: &str) -> Result<u32, ParseIntError> {
+    line.trim().parse()
+}
+
+/// Item of an inventory whose calories are not a valid number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidCalories {
+    /// Line of the input, starting at 1.
+    pub line: usize,
+    /// Elf carrying the item, starting at 1.
+    pub elf: usize,
+    pub value: String,
+}
+
+/// Every item with invalid calories found while parsing the inventories.
+#[derive(Debug, Default)]
+struct InvalidValues {
+    values: Vec<InvalidCalories>,
+    source: Option<ParseIntError>,
+}
+
+impl InvalidValues {
+    fn push(&mut self, line: usize, elf: usize, value: &str, err: ParseIntError) {
+        self.values.push(InvalidCalories {
+            line,
+            elf,
+            value: value.trim().to_string(),
+        });
+        self.source.get_or_insert(err);
+    }
+
+    /// Fails with an error listing every invalid item, positioned at the first
+    /// of them, unless there were none or they are skipped in `lenient` mode.
+    fn check(self, lenient: bool) -> Result<(), Error> {
+        let (Some(first), Some(source)) = (self.values.first(), self.source) else {
+            return Ok(());
+        };
+        if lenient {
+            return Ok(());
+        }
+
+        let mut message = format!(
+            "`{}` is not a valid number of calories (Elf {})",
+            first.value, first.elf
+        );
+        if self.values.len() > 1 {
+            write!(message, ", and {} more:", self.values.len() - 1).unwrap();
+            for invalid in &self.values[1..] {
+                write!(
+                    message,
+                    "\n  line {}: `{}` (Elf {})",
+                    invalid.line, invalid.value, invalid.elf
+                )
+                .unwrap();
+            }
+        }
+
+        Err(Error::parse(message).at(first.line, 1).with_source(source))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Position;
+
+    fn example_input() -> Vec<Elf> {
+        vec![
+            Elf {
+                inventory: vec![1000, 2000, 3000],
+            },
+            Elf {
+                inventory: vec![4000],
+            },
+            Elf {
+                inventory: vec![5000, 6000],
+            },
+            Elf {
+                inventory: vec![7000, 8000, 9000],
+            },
+            Elf {
+                inventory: vec![10000],
+            },
+        ]
+    }
+
+    #[test]
+    fn reads_and_parses_example_input() {
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+        let values = process_input(input, false).unwrap();
+        let expected = example_input();
+
+        assert_eq!(values, expected)
+    }
+
+    #[test]
+    fn reads_and_parses_empty_input() {
+        let input = "";
+        let values = process_input(input, false).unwrap();
+
+        assert!(values.is_empty());
+    }
+
+    #[test]
+    fn reads_and_parses_input_with_only_new_lines() {
+        let input = "\n\n\n\n\n";
+        let values = process_input(input, false).unwrap();
+
+        assert!(values.is_empty());
+    }
+
+    #[test]
+    fn reads_and_parses_input_with_invalid_calories() {
+        let input = "1000\n2000\n\n3000\nabc";
+        let result = process_input(input, false).unwrap_err();
+
+        assert_eq!(result.position(), Some(Position { line: 5, column: 1 }));
+    }
+
+    #[test]
+    fn stream_with_example_input() {
+        let mut input =
+            "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n".as_bytes();
+        let result = Day01::default().stream(&mut input, None).unwrap();
+
+        assert_eq!(result, (Some(24000), Some(45000)));
+    }
+
+    #[test]
+    fn stream_with_invalid_calories() {
+        let mut input = "1000\n2000\n\n3000\nabc".as_bytes();
+        let result = Day01::default().stream(&mut input, None).unwrap_err();
+
+        assert_eq!(result.position(), Some(Position { line: 5, column: 1 }));
+    }
+
+    #[test]
+    fn stream_with_not_enough_elves() {
+        let mut input = "1000\n\n2000".as_bytes();
+
+        assert_eq!(
+            Day01::default()
+                .stream(&mut input, Some(Part::One))
+                .unwrap(),
+            (Some(2000), None)
+        );
+        assert!(Day01::default()
+            .stream(&mut "1000\n\n2000".as_bytes(), None)
+            .is_err());
+    }
+
+    #[test]
+    fn top_totals_keeps_largest_totals() {
+        let mut top_totals = TopTotals::new(3);
+        for total in [6000, 4000, 11000, 24000, 10000] {
+            top_totals.push(total);
+        }
+
+        assert_eq!(top_totals.totals, [24000, 11000, 10000]);
+        assert_eq!(top_totals.sum(3).unwrap(), 45000);
+    }
+
+    #[test]
+    fn top_totals_with_fewer_totals() {
+        let mut top_totals = TopTotals::new(3);
+        top_totals.push(6000);
+        top_totals.push(4000);
+
+        assert_eq!(top_totals.sum(1).unwrap(), 6000);
+        assert!(top_totals.sum(3).is_err());
+    }
+
+    #[test]
+    fn top_elves_with_example_input() {
+        let values = example_input();
+        let result = top_elves(&values, 3).unwrap();
+        let expected = vec![
+            RankedElf {
+                index: 3,
+                total: 24000,
+                rank: 1,
+            },
+            RankedElf {
+                index: 2,
+                total: 11000,
+                rank: 2,
+            },
+            RankedElf {
+                index: 4,
+                total: 10000,
+                rank: 3,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn top_elves_with_ties() {
+        let values = process_input("300\n\n100\n\n300\n\n200\n\n100", false).unwrap();
+        let result: Vec<(usize, usize)> = top_elves(&values, 5)
+            .unwrap()
+            .iter()
+            .map(|elf| (elf.index, elf.rank))
+            .collect();
+
+        assert_eq!(result, vec![(0, 1), (2, 1), (3, 3), (1, 4), (4, 4)]);
+    }
+
+    #[test]
+    fn top_elves_with_not_enough_elves() {
+        let values = example_input();
+        let result = top_elves(&values, 6).unwrap_err();
+
+        assert_eq!(
+            result.to_string(),
+            "not enough Elves, expected at least 6 but found 5"
+        );
+        assert!(top_elves(&[], 1).is_err());
+    }
+
+    #[test]
+    fn part_two_with_configured_top() {
+        let mut day = Day01::default();
+        day.configure("top", Some("2")).unwrap();
+        let result = day.part_two(&example_input()).unwrap();
+
+        assert_eq!(result, 35000);
+    }
+
+    #[test]
+    fn report_ranking_with_example_input() {
+        let result = Day01::default()
+            .report("ranking", &example_input())
+            .unwrap();
+        let expected =
+            "rank  elf  calories\n   1    4     24000\n   2    3     11000\n   3    5     10000\n";
+
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn calorie_stats_with_example_input() {
+        let stats = CalorieStats::new(&example_input()).unwrap();
+
+        assert_eq!(stats.count(), 5);
+        assert_eq!((stats.min(), stats.max()), (4000, 24000));
+        assert_eq!(stats.mean(), 11000.0);
+        assert_eq!(stats.median(), 10000.0);
+        assert_eq!(stats.percentile(25.0), 6000.0);
+        assert_eq!(stats.percentile(90.0), 18800.0);
+        assert_eq!(stats.std_dev(), 48_800_000f64.sqrt());
+    }
+
+    #[test]
+    fn calorie_stats_with_empty_input() {
+        assert!(CalorieStats::new(&[]).is_err());
+    }
+
+    #[test]
+    fn histogram_with_example_input() {
+        let stats = CalorieStats::new(&example_input()).unwrap();
+        let result = stats.histogram(4, 6);
+        let expected = " 4000 -  9000 | ###### 2
+ 9001 - 14001 | ###### 2
+14002 - 19002 | 0
+19003 - 24000 | ### 1
+";
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn reads_and_parses_input_with_windows_line_endings_and_whitespace() {
+        let input = "1000\r\n2000 \r\n \t\r\n\r\n 4000\r\n  \r\n5000\r";
+        let values = process_input(input, false).unwrap();
+        let expected = vec![
+            Elf {
+                inventory: vec![1000, 2000],
+            },
+            Elf {
+                inventory: vec![4000],
+            },
+            Elf {
+                inventory: vec![5000],
+            },
+        ];
+
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn reads_and_parses_input_with_many_invalid_calories() {
+        let input = "1000\nabc\n\n3000\n\n-5\n4000\n1.5";
+        let result = process_input(input, false).unwrap_err();
+        let expected = "line 2, column 1: `abc` is not a valid number of calories (Elf 1), \
+                        and 2 more:\n  line 6: `-5` (Elf 3)\n  line 8: `1.5` (Elf 3)";
+
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn reads_and_parses_input_in_lenient_mode() {
+        let input = "1000\nabc\n\nxyz\n\n-5\n4000";
+        let values = process_input(input, true).unwrap();
+        let expected = vec![
+            Elf {
+                inventory: vec![1000],
+            },
+            Elf { inventory: vec![] },
+            Elf {
+                inventory: vec![4000],
+            },
+        ];
+
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn stream_in_lenient_mode() {
+        let mut day = Day01::default();
+        day.configure("lenient", None).unwrap();
+        let mut input = "1000\nabc\n\n2000\n\n-5\n4000\n\n500".as_bytes();
+        let result = day.stream(&mut input, None).unwrap();
+
+        assert_eq!(result, (Some(4000), Some(7000)));
+    }
+}
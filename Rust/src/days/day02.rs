@@ -0,0 +1,783 @@
+use std::fmt;
+
+use crate::{
+    config::{parse_value, unknown_parameter},
+    report::{unknown_report, Report, Table},
+    Error, ErrorKind, Solution,
+};
+
+/// Shape that can be played, as its position in the cycle of shapes of the
+/// [`Rules`]. Shapes are ordered by their position, which says nothing about
+/// which one wins, see [`Rules::outcome`] for that.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Shape(usize);
+
+impl Shape {
+    pub const ROCK: Shape = Shape(0);
+    pub const PAPER: Shape = Shape(1);
+    pub const SCISSORS: Shape = Shape(2);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Loss => write!(f, "loss"),
+            Outcome::Draw => write!(f, "draw"),
+            Outcome::Win => write!(f, "win"),
+        }
+    }
+}
+
+/// Rules of a game like Rock Paper Scissors with any odd number of shapes in a
+/// cycle, where each shape beats the shapes an odd number of steps before it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    shapes: Vec<String>,
+    /// Code of each shape in the first column of the strategy guide.
+    opponent_codes: Vec<char>,
+    /// Code of each shape in the second column, read as the shape to play.
+    player_codes: Vec<char>,
+    /// Codes of a loss, a draw and a win in the second column, read as the
+    /// desired outcome.
+    outcome_codes: [char; 3],
+    /// Points for playing each shape.
+    shape_scores: Vec<u32>,
+    /// Points for a loss, a draw and a win.
+    outcome_scores: [u32; 3],
+}
+
+impl Rules {
+    /// Rules with the given `shapes`, in the order of the cycle, and their
+    /// codes in each column of the strategy guide. Playing a shape is worth
+    /// its position in the cycle, starting at 1, and outcomes are worth 0, 3
+    /// and 6 points.
+    pub fn cyclic(
+        shapes: &[&str],
+        opponent_codes: &str,
+        player_codes: &str,
+    ) -> Result<Rules, Error> {
+        let opponent_codes: Vec<char> = opponent_codes.chars().collect();
+        let player_codes: Vec<char> = player_codes.chars().collect();
+
+        if shapes.len() < 3 || shapes.len().is_multiple_of(2) {
+            return Err(invalid_rules(format!(
+                "expected an odd number of shapes, at least 3, but found {}",
+                shapes.len()
+            )));
+        }
+        if opponent_codes.len() != shapes.len() || player_codes.len() != shapes.len() {
+            return Err(invalid_rules("expected one code of each column per shape"));
+        }
+
+        Ok(Rules {
+            shapes: shapes.iter().map(|shape| shape.to_string()).collect(),
+            opponent_codes,
+            player_codes,
+            outcome_codes: ['X', 'Y', 'Z'],
+            shape_scores: (1..=shapes.len() as u32).collect(),
+            outcome_scores: [0, 3, 6],
+        })
+    }
+
+    /// Rock Paper Scissors, as in the puzzle.
+    pub fn classic() -> Rules {
+        Rules::cyclic(&["Rock", "Paper", "Scissors"], "ABC", "XYZ").unwrap()
+    }
+
+    /// Rock Paper Scissors Lizard Spock, where the second column uses the codes
+    /// `V` to `Z` for the shapes.
+    pub fn rpsls() -> Rules {
+        Rules::cyclic(
+            &["Rock", "Paper", "Scissors", "Spock", "Lizard"],
+            "ABCDE",
+            "VWXYZ",
+        )
+        .unwrap()
+    }
+
+    /// Sets the points for playing each shape, in the order of the cycle.
+    pub fn with_shape_scores(mut self, scores: Vec<u32>) -> Result<Rules, Error> {
+        if scores.len() != self.shapes.len() {
+            return Err(invalid_rules(format!(
+                "expected {} shape scores but found {}",
+                self.shapes.len(),
+                scores.len()
+            )));
+        }
+
+        self.shape_scores = scores;
+        Ok(self)
+    }
+
+    /// Sets the points for a loss, a draw and a win.
+    pub fn with_outcome_scores(mut self, scores: [u32; 3]) -> Rules {
+        self.outcome_scores = scores;
+        self
+    }
+
+    pub fn shape_count(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn shape_name(&self, shape: Shape) -> &str {
+        &self.shapes[shape.0]
+    }
+
+    /// Outcome of a round for the player.
+    pub fn outcome(&self, player: Shape, opponent: Shape) -> Outcome {
+        match self.steps(opponent, player) {
+            0 => Outcome::Draw,
+            steps if steps % 2 == 1 => Outcome::Win,
+            _ => Outcome::Loss,
+        }
+    }
+
+    /// Shape the player has to play against `opponent` to get the `outcome`.
+    /// When more than one shape does, it is the closest one in the cycle.
+    pub fn shape_for(&self, opponent: Shape, outcome: Outcome) -> Shape {
+        let steps = match outcome {
+            Outcome::Loss => self.shapes.len() - 1,
+            Outcome::Draw => 0,
+            Outcome::Win => 1,
+        };
+
+        Shape((opponent.0 + steps) % self.shapes.len())
+    }
+
+    /// Shape that gets the player the most points against `opponent`. When
+    /// more than one shape does, it is the first one in the cycle.
+    pub fn best_response(&self, opponent: Shape) -> Shape {
+        let mut best = Shape(0);
+        let mut best_points = 0;
+
+        for player in (0..self.shapes.len()).map(Shape) {
+            let points = self.points(&Round { player, opponent });
+            if points > best_points {
+                best = player;
+                best_points = points;
+            }
+        }
+
+        best
+    }
+
+    /// Points the player gets for the `round`.
+    pub fn points(&self, round: &Round) -> u32 {
+        let outcome = self.outcome(round.player, round.opponent);
+
+        self.outcome_scores[outcome as usize] + self.shape_scores[round.player.0]
+    }
+
+    /// Number of steps forward in the cycle from `from` to `to`.
+    fn steps(&self, from: Shape, to: Shape) -> usize {
+        (to.0 + self.shapes.len() - from.0) % self.shapes.len()
+    }
+}
+
+fn invalid_rules(message: impl Into<String>) -> Error {
+    Error::new(ErrorKind::Config, message)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Round {
+    player: Shape,
+    opponent: Shape,
+}
+
+/// Rounds of the strategy guide, read with both interpretations of the second
+/// column: as the shape to play and as the desired outcome of the round. A
+/// guide with only the first column has just the shapes of the opponent.
+#[derive(Debug)]
+pub struct StrategyGuide {
+    opponents: Vec<Shape>,
+    rounds_with_guess: Option<Vec<Round>>,
+    rounds_with_strategy: Option<Vec<Round>>,
+}
+
+impl StrategyGuide {
+    fn rounds_with_guess(&self) -> Result<&[Round], Error> {
+        self.rounds_with_guess.as_deref().ok_or_else(missing_column)
+    }
+
+    fn rounds_with_strategy(&self) -> Result<&[Round], Error> {
+        self.rounds_with_strategy
+            .as_deref()
+            .ok_or_else(missing_column)
+    }
+}
+
+fn missing_column() -> Error {
+    Error::solve("the strategy guide only has the shapes of the opponent")
+}
+
+#[derive(Debug)]
+pub struct Day02 {
+    pub rules: Rules,
+    /// Scores set by the `shape-scores` parameter, kept when the rules change.
+    shape_scores: Option<Vec<u32>>,
+    /// Scores set by the `outcome-scores` parameter, kept when the rules
+    /// change.
+    outcome_scores: Option<[u32; 3]>,
+}
+
+impl Default for Day02 {
+    fn default() -> Self {
+        Day02 {
+            rules: Rules::classic(),
+            shape_scores: None,
+            outcome_scores: None,
+        }
+    }
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Rock Paper Scissors";
+    const EXAMPLE: &'static str = include_str!("../../inputs/day02_example.txt");
+    const REPORTS: &'static [&'static str] =
+        &["rounds-guess", "rounds-strategy", "optimal", "shortfall"];
+
+    type Input = StrategyGuide;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        let opponents = parse_opponents(input, &self.rules)?;
+
+        if !opponents.is_empty() && input.lines().all(|line| !line.trim().contains(' ')) {
+            return Ok(StrategyGuide {
+                opponents,
+                rounds_with_guess: None,
+                rounds_with_strategy: None,
+            });
+        }
+
+        Ok(StrategyGuide {
+            opponents,
+            rounds_with_guess: Some(parse_input_with_guess(input, &self.rules)?),
+            rounds_with_strategy: Some(parse_input_with_strategy(input, &self.rules)?),
+        })
+    }
+
+    fn part_one(&self, guide: &Self::Input) -> Result<Self::PartOne, Error> {
+        Ok(process_rounds(guide.rounds_with_guess()?, &self.rules))
+    }
+
+    fn part_two(&self, guide: &Self::Input) -> Result<Self::PartTwo, Error> {
+        Ok(process_rounds(guide.rounds_with_strategy()?, &self.rules))
+    }
+
+    fn configure(&mut self, name: &str, value: Option<&str>) -> Result<(), Error> {
+        match name {
+            "rules" => {
+                let rules = match parse_value::<String>(name, value)?.as_str() {
+                    "classic" => Rules::classic(),
+                    "rpsls" => Rules::rpsls(),
+                    other => {
+                        return Err(invalid_rules(format!(
+                            "unknown rules `{}`, expected `classic` or `rpsls`",
+                            other
+                        )))
+                    }
+                };
+                self.set_rules(rules)?;
+            }
+            "shape-scores" => {
+                let scores = parse_scores(name, value)?;
+                self.rules = self.rules.clone().with_shape_scores(scores.clone())?;
+                self.shape_scores = Some(scores);
+            }
+            "outcome-scores" => {
+                let scores = parse_scores(name, value)?;
+                let scores = scores.try_into().map_err(|scores: Vec<u32>| {
+                    invalid_rules(format!(
+                        "expected 3 outcome scores but found {}",
+                        scores.len()
+                    ))
+                })?;
+                self.rules = self.rules.clone().with_outcome_scores(scores);
+                self.outcome_scores = Some(scores);
+            }
+            _ => {
+                return Err(unknown_parameter(
+                    name,
+                    &["rules", "shape-scores", "outcome-scores"],
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
+    fn report(&self, name: &str, guide: &Self::Input) -> Result<Report, Error> {
+        match name {
+            "rounds-guess" => Ok(Report::Table(self.rounds_table(guide.rounds_with_guess()?))),
+            "rounds-strategy" => Ok(Report::Table(
+                self.rounds_table(guide.rounds_with_strategy()?),
+            )),
+            "optimal" => {
+                let plan = optimal_plan(&guide.opponents, &self.rules);
+
+                Ok(Report::Table(self.rounds_table(&plan)))
+            }
+            "shortfall" => {
+                let plan = optimal_plan(&guide.opponents, &self.rules);
+                let optimal = process_rounds(&plan, &self.rules);
+                let mut table = Table::new(&["interpretation", "score", "optimal", "shortfall"]);
+                let interpretations = [
+                    ("guess", guide.rounds_with_guess()?),
+                    ("strategy", guide.rounds_with_strategy()?),
+                    ("optimal", &plan),
+                ];
+
+                for (interpretation, rounds) in interpretations {
+                    let score = process_rounds(rounds, &self.rules);
+                    table.push(vec![
+                        interpretation.to_string(),
+                        score.to_string(),
+                        optimal.to_string(),
+                        (optimal - score).to_string(),
+                    ]);
+                }
+
+                Ok(Report::Table(table))
+            }
+            _ => Err(unknown_report(name, Self::REPORTS)),
+        }
+    }
+}
+
+impl Day02 {
+    /// Sets the `rules`, keeping the scores already given by the parameters in
+    /// place of their own.
+    fn set_rules(&mut self, rules: Rules) -> Result<(), Error> {
+        let mut rules = rules;
+
+        if let Some(scores) = &self.shape_scores {
+            rules = rules.with_shape_scores(scores.clone())?;
+        }
+        if let Some(scores) = self.outcome_scores {
+            rules = rules.with_outcome_scores(scores);
+        }
+
+        self.rules = rules;
+        Ok(())
+    }
+
+    /// Shapes, outcome and points of each of the `rounds`, along with the
+    /// total score after each of them.
+    fn rounds_table(&self, rounds: &[Round]) -> Table {
+        let mut table = Table::new(&["round", "opponent", "player", "outcome", "points", "total"]);
+        let mut total = 0;
+
+        for (i, round) in rounds.iter().enumerate() {
+            let points = self.rules.points(round);
+            total += points;
+            table.push(vec![
+                (i + 1).to_string(),
+                self.rules.shape_name(round.opponent).to_string(),
+                self.rules.shape_name(round.player).to_string(),
+                self.rules.outcome(round.player, round.opponent).to_string(),
+                points.to_string(),
+                total.to_string(),
+            ]);
+        }
+
+        table
+    }
+}
+
+/// Parses a list of scores separated by commas.
+fn parse_scores(name: &str, value: Option<&str>) -> Result<Vec<u32>, Error> {
+    parse_value::<String>(name, value)?
+        .split(',')
+        .map(|score| parse_value(name, Some(score.trim())))
+        .collect()
+}
+
+fn process_rounds(rounds: &[Round], rules: &Rules) -> u32 {
+    let mut score = 0;
+
+    for round in rounds {
+        score += rules.points(round);
+    }
+
+    score
+}
+
+/// Rounds against the given `opponents`, where the player plays the shape
+/// that gets the most points in each of them, see [`Rules::best_response`].
+fn optimal_plan(opponents: &[Shape], rules: &Rules) -> Vec<Round> {
+    opponents
+        .iter()
+        .map(|&opponent| Round {
+            player: rules.best_response(opponent),
+            opponent,
+        })
+        .collect()
+}
+
+/// Shapes of the opponent in the first column of each round, ignoring the
+/// second column.
+fn parse_opponents(input: &str, rules: &Rules) -> Result<Vec<Shape>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            line.split(' ')
+                .next()
+                .and_then(|token| find_code(&rules.opponent_codes, token))
+                .map(Shape)
+                .ok_or_else(|| invalid_shape(line, i, 1))
+        })
+        .collect()
+}
+
+fn parse_input_with_guess(input: &str, rules: &Rules) -> Result<Vec<Round>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let tokens: Vec<&str> = line.split(' ').collect();
+            let opponent = tokens
+                .first()
+                .and_then(|token| find_code(&rules.opponent_codes, token))
+                .map(Shape)
+                .ok_or_else(|| invalid_shape(line, i, 1))?;
+            let player = tokens
+                .last()
+                .and_then(|token| find_code(&rules.player_codes, token))
+                .map(Shape)
+                .ok_or_else(|| invalid_shape(line, i, last_token_column(line)))?;
+            Ok(Round { opponent, player })
+        })
+        .collect()
+}
+
+fn parse_input_with_strategy(input: &str, rules: &Rules) -> Result<Vec<Round>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let tokens: Vec<&str> = line.split(' ').collect();
+            let opponent = tokens
+                .first()
+                .and_then(|token| find_code(&rules.opponent_codes, token))
+                .map(Shape)
+                .ok_or_else(|| invalid_shape(line, i, 1))?;
+            let outcome = match tokens
+                .last()
+                .and_then(|token| find_code(&rules.outcome_codes, token))
+            {
+                Some(0) => Outcome::Loss,
+                Some(1) => Outcome::Draw,
+                Some(_) => Outcome::Win,
+                None => return Err(invalid_shape(line, i, last_token_column(line))),
+            };
+            let player = rules.shape_for(opponent, outcome);
+            Ok(Round { opponent, player })
+        })
+        .collect()
+}
+
+/// Position of the single character `token` in the `codes`.
+fn find_code(codes: &[char], token: &str) -> Option<usize> {
+    let mut chars = token.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(code), None) => codes.iter().position(|&c| c == code),
+        _ => None,
+    }
+}
+
+fn invalid_shape(line: &str, line_index: usize, column: usize) -> Error {
+    Error::parse(format!("invalid shape in round `{}`", line)).at(line_index + 1, column)
+}
+
+fn last_token_column(line: &str) -> usize {
+    line.rfind(' ').map_or(1, |i| i + 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, Position};
+
+    #[test]
+    fn read_and_parse_input_with_example_input_with_guess() {
+        let result = example_rounds_with_guess();
+        let expected = vec![
+            Round {
+                player: Shape::PAPER,
+                opponent: Shape::ROCK,
+            },
+            Round {
+                player: Shape::ROCK,
+                opponent: Shape::PAPER,
+            },
+            Round {
+                player: Shape::SCISSORS,
+                opponent: Shape::SCISSORS,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn read_and_parse_input_with_example_input_with_strategy() {
+        let result = example_rounds_with_strategy();
+        let expected = vec![
+            Round {
+                player: Shape::ROCK,
+                opponent: Shape::ROCK,
+            },
+            Round {
+                player: Shape::ROCK,
+                opponent: Shape::PAPER,
+            },
+            Round {
+                player: Shape::ROCK,
+                opponent: Shape::SCISSORS,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn read_and_parse_input_with_empty_input() {
+        let input = "\n\n\n";
+        let values = read_input(&mut input.as_bytes()).unwrap();
+        let result = parse_input_with_guess(&values, &Rules::classic()).unwrap();
+
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn parse_input_with_guess_with_invalid_shape() {
+        let input = "A Y\nB W\nC Z";
+        let result = parse_input_with_guess(input, &Rules::classic()).unwrap_err();
+
+        assert_eq!(result.position(), Some(Position { line: 2, column: 3 }));
+    }
+
+    #[test]
+    fn process_rounds_with_example_input_with_guess() {
+        let rounds = example_rounds_with_guess();
+        let value = process_rounds(&rounds, &Rules::classic());
+
+        assert_eq!(value, 15);
+    }
+
+    #[test]
+    fn process_rounds_with_example_input_with_strategy() {
+        let rounds = example_rounds_with_strategy();
+        let value = process_rounds(&rounds, &Rules::classic());
+
+        assert_eq!(value, 12);
+    }
+
+    #[test]
+    fn outcome_with_rpsls_rules() {
+        let rules = Rules::rpsls();
+        let [rock, paper, scissors, spock, lizard] = [0, 1, 2, 3, 4].map(Shape);
+        let test_cases = [
+            (spock, rock, Outcome::Win),
+            (spock, scissors, Outcome::Win),
+            (lizard, paper, Outcome::Win),
+            (lizard, spock, Outcome::Win),
+            (rock, lizard, Outcome::Win),
+            (paper, lizard, Outcome::Loss),
+            (rock, paper, Outcome::Loss),
+            (spock, spock, Outcome::Draw),
+        ];
+
+        for (player, opponent, expected) in test_cases {
+            assert_eq!(rules.outcome(player, opponent), expected);
+        }
+    }
+
+    #[test]
+    fn shape_for_gets_the_outcome() {
+        let rules = Rules::rpsls();
+
+        for opponent in (0..5).map(Shape) {
+            for outcome in [Outcome::Loss, Outcome::Draw, Outcome::Win] {
+                let player = rules.shape_for(opponent, outcome);
+                assert_eq!(rules.outcome(player, opponent), outcome);
+            }
+        }
+    }
+
+    #[test]
+    fn parse_input_with_rpsls_rules() {
+        let rules = Rules::rpsls();
+        let input = "D V\nE Z\nA Y";
+        let result = parse_input_with_guess(input, &rules).unwrap();
+
+        assert_eq!(process_rounds(&result, &rules), 1 + (3 + 5) + (6 + 4));
+        assert!(parse_input_with_guess("F V", &rules).is_err());
+    }
+
+    #[test]
+    fn process_rounds_with_custom_scores() {
+        let rules = Rules::classic()
+            .with_shape_scores(vec![10, 20, 30])
+            .unwrap()
+            .with_outcome_scores([1, 2, 4]);
+        let rounds = example_rounds_with_guess();
+
+        assert_eq!(process_rounds(&rounds, &rules), 24 + 11 + 32);
+    }
+
+    #[test]
+    fn cyclic_with_invalid_rules() {
+        assert!(Rules::cyclic(&["Rock", "Paper"], "AB", "XY").is_err());
+        assert!(Rules::cyclic(&["A", "B", "C", "D"], "ABCD", "WXYZ").is_err());
+        assert!(Rules::cyclic(&["Rock", "Paper", "Scissors"], "ABC", "XY").is_err());
+        assert!(Rules::classic().with_shape_scores(vec![1, 2]).is_err());
+    }
+
+    #[test]
+    fn configure_with_rules_and_scores() {
+        let mut day = Day02::default();
+        day.configure("rules", Some("rpsls")).unwrap();
+        day.configure("outcome-scores", Some("0,1,2")).unwrap();
+
+        assert_eq!(day.rules, Rules::rpsls().with_outcome_scores([0, 1, 2]));
+        assert!(day.configure("rules", Some("chess")).is_err());
+        assert!(day.configure("outcome-scores", Some("0,1")).is_err());
+    }
+
+    #[test]
+    fn configure_with_scores_before_rules() {
+        let mut day = Day02::default();
+        day.configure("outcome-scores", Some("0,1,2")).unwrap();
+        day.configure("shape-scores", Some("5,4,3,2,1"))
+            .unwrap_err();
+        day.configure("rules", Some("rpsls")).unwrap();
+
+        assert_eq!(day.rules, Rules::rpsls().with_outcome_scores([0, 1, 2]));
+
+        let mut day = Day02::default();
+        day.configure("shape-scores", Some("3,2,1")).unwrap();
+        let result = day.configure("rules", Some("rpsls")).unwrap_err();
+
+        assert_eq!(result.kind(), ErrorKind::Config);
+        assert_eq!(result.message(), "expected 5 shape scores but found 3");
+    }
+
+    #[test]
+    fn optimal_plan_with_example_input() {
+        let rules = Rules::classic();
+        let opponents = [Shape::ROCK, Shape::PAPER, Shape::SCISSORS];
+        let result = optimal_plan(&opponents, &rules);
+        let expected = vec![
+            Round {
+                player: Shape::PAPER,
+                opponent: Shape::ROCK,
+            },
+            Round {
+                player: Shape::SCISSORS,
+                opponent: Shape::PAPER,
+            },
+            Round {
+                player: Shape::ROCK,
+                opponent: Shape::SCISSORS,
+            },
+        ];
+
+        assert_eq!(result, expected);
+        assert_eq!(process_rounds(&result, &rules), 24);
+    }
+
+    #[test]
+    fn best_response_with_custom_scores() {
+        let rules = Rules::classic().with_shape_scores(vec![1, 2, 10]).unwrap();
+
+        assert_eq!(rules.best_response(Shape::ROCK), Shape::SCISSORS);
+        assert_eq!(rules.best_response(Shape::PAPER), Shape::SCISSORS);
+        assert_eq!(rules.best_response(Shape::SCISSORS), Shape::SCISSORS);
+    }
+
+    #[test]
+    fn report_shortfall_with_example_input() {
+        let day = Day02::default();
+        let guide = day.parse("A Y\nB X\nC Z").unwrap();
+        let result = day.report("shortfall", &guide).unwrap();
+        let expected = "interpretation  score  optimal  shortfall
+guess              15       24          9
+strategy           12       24         12
+optimal            24       24          0
+";
+
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn report_optimal_with_opponents_only() {
+        let day = Day02::default();
+        let guide = day.parse("A\nB\nC\n").unwrap();
+        let result = day.report("optimal", &guide).unwrap();
+        let expected = "round  opponent  player    outcome  points  total
+    1  Rock      Paper     win           8      8
+    2  Paper     Scissors  win           9     17
+    3  Scissors  Rock      win           7     24
+";
+
+        assert_eq!(result.to_string(), expected);
+        assert_eq!(
+            day.part_one(&guide).unwrap_err().message(),
+            "the strategy guide only has the shapes of the opponent"
+        );
+        assert!(day.report("shortfall", &guide).is_err());
+    }
+
+    #[test]
+    fn report_rounds_with_example_input() {
+        let day = Day02::default();
+        let guide = day.parse("A Y\nB X\nC Z").unwrap();
+        let result = day.report("rounds-strategy", &guide).unwrap();
+        let expected = "round  opponent  player  outcome  points  total
+    1  Rock      Rock    draw          4      4
+    2  Paper     Rock    loss          1      5
+    3  Scissors  Rock    win           7     12
+";
+
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn shapes_can_be_sorted() {
+        let mut shapes = vec![Shape::SCISSORS, Shape::ROCK, Shape::PAPER, Shape::ROCK];
+        shapes.sort();
+        shapes.dedup();
+
+        assert_eq!(shapes, vec![Shape::ROCK, Shape::PAPER, Shape::SCISSORS]);
+    }
+
+    fn example_rounds_with_guess() -> Vec<Round> {
+        let input = "A Y\nB X\nC Z";
+        let values = read_input(&mut input.as_bytes()).unwrap();
+
+        parse_input_with_guess(&values, &Rules::classic()).unwrap()
+    }
+
+    fn example_rounds_with_strategy() -> Vec<Round> {
+        let input = "A Y\nB X\nC Z";
+        let values = read_input(&mut input.as_bytes()).unwrap();
+
+        parse_input_with_strategy(&values, &Rules::classic()).unwrap()
+    }
+}
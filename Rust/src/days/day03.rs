@@ -0,0 +1,417 @@
+use std::num::NonZeroUsize;
+
+use crate::{
+    config::{parse_value, unknown_parameter},
+    report::{unknown_report, Report, Table},
+    Error, Solution,
+};
+
+/// Item types of a rucksack, split evenly between its two compartments.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Rucksack {
+    /// Line of the input with the rucksack, starting at 1.
+    line: usize,
+    /// Sets of the item types of each compartment, see [`item_mask`].
+    compartments: (u64, u64),
+}
+
+impl Rucksack {
+    /// Set of the item types in any of the compartments.
+    fn items(&self) -> u64 {
+        self.compartments.0 | self.compartments.1
+    }
+
+    /// Item type that appears in both compartments of the rucksack. There must
+    /// be exactly one of them.
+    fn repeated_item(&self) -> Result<char, Error> {
+        let (first, second) = self.compartments;
+
+        single_item(first & second).map_err(|common| {
+            let message = if common.is_empty() {
+                "no item type appears in both compartments".to_string()
+            } else {
+                format!(
+                    "more than one item type appears in both compartments: {}",
+                    common
+                )
+            };
+
+            Error::solve(message).at(self.line, 1)
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Day03 {
+    /// Number of Elves of each group in part two.
+    pub group_size: NonZeroUsize,
+}
+
+impl Default for Day03 {
+    fn default() -> Self {
+        Day03 {
+            group_size: NonZeroUsize::new(3).unwrap(),
+        }
+    }
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
+    const EXAMPLE: &'static str = include_str!("../../inputs/day03_example.txt");
+    const REPORTS: &'static [&'static str] = &["duplicates"];
+
+    type Input = Vec<Rucksack>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        parse_rucksacks(input)
+    }
+
+    fn part_one(&self, rucksacks: &Self::Input) -> Result<Self::PartOne, Error> {
+        get_sum_of_priorities_for_repeated_items(rucksacks)
+    }
+
+    fn part_two(&self, rucksacks: &Self::Input) -> Result<Self::PartTwo, Error> {
+        get_sum_of_priorities_for_groups(rucksacks, self.group_size.get())
+    }
+
+    fn configure(&mut self, name: &str, value: Option<&str>) -> Result<(), Error> {
+        match name {
+            "group-size" => self.group_size = parse_value(name, value)?,
+            _ => return Err(unknown_parameter(name, &["group-size"])),
+        }
+
+        Ok(())
+    }
+
+    fn report(&self, name: &str, rucksacks: &Self::Input) -> Result<Report, Error> {
+        match name {
+            "duplicates" => {
+                let mut table = Table::new(&["rucksack", "line", "item", "priority"]);
+
+                for (i, rucksack) in rucksacks.iter().enumerate() {
+                    let item = rucksack.repeated_item()?;
+                    table.push(vec![
+                        (i + 1).to_string(),
+                        rucksack.line.to_string(),
+                        item.to_string(),
+                        get_priority(&item).unwrap_or(0).to_string(),
+                    ]);
+                }
+
+                Ok(Report::Table(table))
+            }
+            _ => Err(unknown_report(name, Self::REPORTS)),
+        }
+    }
+}
+
+/// Parses a rucksack from each line that is not blank, checking that every
+/// item type is a letter and that the items can be split evenly between both
+/// compartments. Whitespace at the end of the lines, such as the `\r` of
+/// Windows line endings, is ignored.
+fn parse_rucksacks(input: &str) -> Result<Vec<Rucksack>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim_end()))
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(line_number, line)| {
+            if let Some((column, item)) = line
+                .chars()
+                .enumerate()
+                .find(|(_, item)| get_priority(item).is_none())
+            {
+                return Err(Error::parse(format!(
+                    "invalid item type `{}`, expected a letter",
+                    item.escape_debug()
+                ))
+                .at(line_number, column + 1));
+            }
+
+            if !line.len().is_multiple_of(2) {
+                return Err(Error::parse(format!(
+                    "rucksack has an odd number of items ({}), so they cannot be split \
+                     between both compartments",
+                    line.len()
+                ))
+                .at(line_number, 1));
+            }
+
+            let (first, second) = line.split_at(line.len() / 2);
+
+            Ok(Rucksack {
+                line: line_number,
+                compartments: (item_mask(first), item_mask(second)),
+            })
+        })
+        .collect()
+}
+
+/// Set of the item types of the `items`, where the bit `n` is set if the item
+/// type with priority `n + 1` is one of them.
+fn item_mask(items: &str) -> u64 {
+    items
+        .chars()
+        .filter_map(|item| get_priority(&item))
+        .fold(0, |mask, priority| mask | 1 << (priority - 1))
+}
+
+/// The only item type of the `mask`, or all of its item types (none or more
+/// than one) when there is not exactly one.
+fn single_item(mask: u64) -> Result<char, String> {
+    if mask.count_ones() == 1 {
+        return Ok(item_type(mask.trailing_zeros() + 1));
+    }
+
+    Err((0..52)
+        .filter(|bit| mask & 1 << bit != 0)
+        .map(|bit| item_type(bit + 1))
+        .collect())
+}
+
+/// Item type with the given `priority`, from 1 to 52.
+fn item_type(priority: u32) -> char {
+    match priority {
+        1..=26 => (b'a' + (priority - 1) as u8) as char,
+        _ => (b'A' + (priority - 27) as u8) as char,
+    }
+}
+
+/// Priority of an item type, from 1 to 26 for `a` to `z` and from 27 to 52 for
+/// `A` to `Z`. Any other character is not an item type.
+fn get_priority(item_type: &char) -> Option<u32> {
+    match item_type {
+        'a'..='z' => Some(*item_type as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(*item_type as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+fn get_sum_of_priorities_for_repeated_items(rucksacks: &[Rucksack]) -> Result<u32, Error> {
+    rucksacks
+        .iter()
+        .map(|rucksack| Ok(get_priority(&rucksack.repeated_item()?).unwrap_or(0)))
+        .sum()
+}
+
+/// Sum of the priorities of the item type carried by every Elf of each group of
+/// `group_size` Elves. Every group must be complete.
+fn get_sum_of_priorities_for_groups(
+    rucksacks: &[Rucksack],
+    group_size: usize,
+) -> Result<u32, Error> {
+    rucksacks
+        .chunks(group_size)
+        .map(|group| {
+            if group.len() < group_size {
+                return Err(Error::solve(format!(
+                    "incomplete group of {} rucksacks, expected {}",
+                    group.len(),
+                    group_size
+                ))
+                .at(group[0].line, 1));
+            }
+
+            get_common_item_from_group(group).map_err(|err| err.at(group[0].line, 1))
+        })
+        .sum()
+}
+
+fn get_common_item_from_group(group: &[Rucksack]) -> Result<u32, Error> {
+    let common = group
+        .iter()
+        .map(Rucksack::items)
+        .fold(u64::MAX, |common, mask| common & mask);
+
+    match single_item(common) {
+        Ok(item) => Ok(get_priority(&item).unwrap_or(0)),
+        Err(items) if items.is_empty() => Err(Error::solve(
+            "no item type is carried by every Elf of the group",
+        )),
+        Err(items) => Err(Error::solve(format!(
+            "more than one item type is carried by every Elf of the group: {}",
+            items
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Position;
+
+    const EXAMPLE_INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn parse_input_with_first_example_input() {
+        let input = "vJrwpWtwJgWrhcsFMMfFFhFp\n";
+        let values = parse_rucksacks(input).unwrap();
+
+        assert_eq!(
+            values[0].compartments,
+            (item_mask("vJrwpWtwJgWr"), item_mask("hcsFMMfFFhFp"))
+        );
+    }
+
+    #[test]
+    fn parse_input_with_windows_line_endings() {
+        let input = "vJrwpWtwJgWrhcsFMMfFFhFp\r\nPmmdzqPrVvPwwTWBwg\r\n\r\n";
+        let values = parse_rucksacks(input).unwrap();
+        let expected = vec![
+            Rucksack {
+                line: 1,
+                compartments: (item_mask("vJrwpWtwJgWr"), item_mask("hcsFMMfFFhFp")),
+            },
+            Rucksack {
+                line: 2,
+                compartments: (item_mask("PmmdzqPrV"), item_mask("vPwwTWBwg")),
+            },
+        ];
+
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn parse_input_with_invalid_rucksacks() {
+        let test_cases = [
+            (
+                "vJrwpWtwJgWrhcsFMMfFFhFp\nabc",
+                Position { line: 2, column: 1 },
+                "rucksack has an odd number of items (3), so they cannot be split between both \
+                 compartments",
+            ),
+            (
+                "vJrwpWtw1gWr",
+                Position { line: 1, column: 9 },
+                "invalid item type `1`, expected a letter",
+            ),
+            (
+                "abcdé",
+                Position { line: 1, column: 5 },
+                "invalid item type `é`, expected a letter",
+            ),
+        ];
+
+        for (input, position, message) in test_cases {
+            let result = parse_rucksacks(input).unwrap_err();
+
+            assert_eq!(result.position(), Some(position));
+            assert_eq!(result.message(), message);
+        }
+    }
+
+    #[test]
+    fn find_repeated_char_with_first_jexample_input() {
+        let first = item_mask("vJrwpWtwJgWr");
+        let second = item_mask("hcsFMMfFFhFp");
+        let result = single_item(first & second);
+
+        assert_eq!(result, Ok('p'));
+    }
+
+    #[test]
+    fn item_mask_with_every_item_type() {
+        let items = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+        assert_eq!(item_mask(items), (1 << 52) - 1);
+        assert_eq!(item_mask("aaZ"), 1 | 1 << 51);
+        assert_eq!(single_item(item_mask(items)), Err(items.to_string()));
+        assert_eq!(single_item(item_mask("ZZ")), Ok('Z'));
+        assert_eq!(single_item(0), Err(String::new()));
+    }
+
+    #[test]
+    fn repeated_item_with_ambiguous_rucksack() {
+        let rucksacks = parse_rucksacks("abab").unwrap();
+        let result = rucksacks[0].repeated_item().unwrap_err();
+
+        assert_eq!(
+            result.message(),
+            "more than one item type appears in both compartments: ab"
+        );
+    }
+
+    #[test]
+    fn get_priority_with_first_example_input() {
+        let expected = Some(16);
+        let result = get_priority(&'p');
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn get_priority_with_second_example_input() {
+        let expected = Some(38);
+        let result = get_priority(&'L');
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn get_priority_with_invalid_item_types() {
+        for item in ['0', ' ', '\r', 'é', '[', '`'] {
+            assert_eq!(get_priority(&item), None);
+        }
+    }
+
+    #[test]
+    fn get_sum_of_priorities_for_repeated_items_with_example_input() {
+        let rucksacks = parse_rucksacks(EXAMPLE_INPUT).unwrap();
+        let result = get_sum_of_priorities_for_repeated_items(&rucksacks).unwrap();
+
+        assert_eq!(result, 157);
+    }
+
+    #[test]
+    fn get_sum_of_priorities_for_groups_with_example_input() {
+        let rucksacks = parse_rucksacks(EXAMPLE_INPUT).unwrap();
+        let result = get_sum_of_priorities_for_groups(&rucksacks, 3).unwrap();
+
+        assert_eq!(result, 70);
+    }
+
+    #[test]
+    fn get_sum_of_priorities_for_groups_with_other_sizes() {
+        let rucksacks = parse_rucksacks("abcd\naefg\nhaij\nklhm").unwrap();
+        let result = get_sum_of_priorities_for_groups(&rucksacks, 2).unwrap();
+
+        assert_eq!(result, 1 + 8);
+        assert!(get_sum_of_priorities_for_groups(&rucksacks, 1).is_err());
+    }
+
+    #[test]
+    fn get_sum_of_priorities_for_groups_with_incomplete_group() {
+        let rucksacks = parse_rucksacks("abcd\naefg\nhaij").unwrap();
+        let result = get_sum_of_priorities_for_groups(&rucksacks, 2).unwrap_err();
+
+        assert_eq!(result.position(), Some(Position { line: 3, column: 1 }));
+        assert_eq!(
+            result.message(),
+            "incomplete group of 1 rucksacks, expected 2"
+        );
+    }
+
+    #[test]
+    fn report_duplicates_with_example_input() {
+        let rucksacks = parse_rucksacks(EXAMPLE_INPUT).unwrap();
+        let result = Day03::default().report("duplicates", &rucksacks).unwrap();
+        let expected = "rucksack  line  item  priority
+       1     1  p           16
+       2     2  L           38
+       3     3  P           42
+       4     4  v           22
+       5     5  t           20
+       6     6  s           19
+";
+
+        assert_eq!(result.to_string(), expected);
+    }
+}
@@ -0,0 +1,717 @@
+use std::{fmt, num::NonZeroUsize};
+
+use crate::{
+    config::{parse_value, unknown_parameter},
+    report::{unknown_report, Report, Table},
+    Error, Solution,
+};
+
+/// Sections from `start` to `end`, both included. A range always has at least
+/// one section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SectionRange {
+    start: u32,
+    end: u32,
+}
+
+impl SectionRange {
+    /// Range from `start` to `end`, unless it ends before it starts.
+    pub fn new(start: u32, end: u32) -> Option<SectionRange> {
+        (start <= end).then_some(SectionRange { start, end })
+    }
+
+    pub fn start(&self) -> u32 {
+        self.start
+    }
+
+    pub fn end(&self) -> u32 {
+        self.end
+    }
+
+    /// Number of sections of the range.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u64 {
+        u64::from(self.end - self.start) + 1
+    }
+
+    pub fn contains(&self, section: u32) -> bool {
+        (self.start..=self.end).contains(&section)
+    }
+
+    /// Whether every section of `other` is in this range.
+    pub fn contains_range(&self, other: &SectionRange) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Sections in both ranges.
+    pub fn intersection(&self, other: &SectionRange) -> Option<SectionRange> {
+        SectionRange::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Sections in either range, as a single range when they overlap or are
+    /// next to each other, or as both ranges in order otherwise.
+    pub fn union(&self, other: &SectionRange) -> Vec<SectionRange> {
+        merge(&[*self, *other])
+    }
+
+    /// Sections of this range that are not in `other`, as up to two ranges in
+    /// order.
+    pub fn difference(&self, other: &SectionRange) -> Vec<SectionRange> {
+        if self.intersection(other).is_none() {
+            return vec![*self];
+        }
+
+        let before = other
+            .start
+            .checked_sub(1)
+            .and_then(|end| SectionRange::new(self.start, end));
+        let after = other
+            .end
+            .checked_add(1)
+            .and_then(|start| SectionRange::new(start, self.end));
+
+        before.into_iter().chain(after).collect()
+    }
+
+    /// Whether either range contains the other one.
+    pub fn fully_overlap(&self, other: &SectionRange) -> bool {
+        self.contains_range(other) || other.contains_range(self)
+    }
+
+    /// Whether the ranges have any section in common.
+    pub fn partially_overlap(&self, other: &SectionRange) -> bool {
+        self.intersection(other).is_some()
+    }
+}
+
+impl fmt::Display for SectionRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// Sections in any of the `ranges`, as disjoint ranges in order. Ranges that
+/// overlap or are next to each other are merged.
+pub fn merge(ranges: &[SectionRange]) -> Vec<SectionRange> {
+    let mut ranges = ranges.to_vec();
+    ranges.sort_unstable();
+
+    let mut merged: Vec<SectionRange> = vec![];
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end.saturating_add(1) => {
+                last.end = last.end.max(range.end);
+            }
+            _ => merged.push(range),
+        }
+    }
+
+    merged
+}
+
+/// Sections of `bounds` that are in none of the `ranges`, as disjoint ranges
+/// in order.
+pub fn uncovered(ranges: &[SectionRange], bounds: SectionRange) -> Vec<SectionRange> {
+    let mut uncovered = vec![];
+    // First section of the bounds not known to be covered yet, if any.
+    let mut next = Some(bounds.start);
+
+    for range in merge(ranges) {
+        let Some(start) = next else {
+            break;
+        };
+        if range.start > bounds.end {
+            break;
+        }
+        if range.end < start {
+            continue;
+        }
+
+        if range.start > start {
+            uncovered.push(SectionRange {
+                start,
+                end: range.start - 1,
+            });
+        }
+        next = range.end.checked_add(1);
+    }
+
+    if let Some(gap) = next.and_then(|start| SectionRange::new(start, bounds.end)) {
+        uncovered.push(gap);
+    }
+
+    uncovered
+}
+
+/// Number of `ranges` each section is in, as disjoint ranges in order, each
+/// with the number of ranges its sections are in. Sections in none of them are
+/// left out.
+pub fn coverage(ranges: &[SectionRange]) -> Vec<(SectionRange, usize)> {
+    let mut events: Vec<(u64, i64)> = ranges
+        .iter()
+        .flat_map(|range| [(range.start as u64, 1), (range.end as u64 + 1, -1)])
+        .collect();
+    events.sort_unstable();
+
+    let mut coverage = vec![];
+    let mut count = 0;
+    for (i, &(section, change)) in events.iter().enumerate() {
+        count += change;
+
+        match events.get(i + 1) {
+            Some(&(next, _)) if next > section && count > 0 => coverage.push((
+                SectionRange {
+                    start: section as u32,
+                    end: (next - 1) as u32,
+                },
+                count as usize,
+            )),
+            _ => {}
+        }
+    }
+
+    coverage
+}
+
+/// Largest number of `ranges` any section is in, along with the sections that
+/// are in that many ranges, as disjoint ranges in order.
+pub fn max_coverage(ranges: &[SectionRange]) -> (usize, Vec<SectionRange>) {
+    let coverage = coverage(ranges);
+    let max = coverage.iter().map(|&(_, count)| count).max().unwrap_or(0);
+    let busiest: Vec<SectionRange> = coverage
+        .into_iter()
+        .filter(|&(_, count)| count == max)
+        .map(|(range, _)| range)
+        .collect();
+
+    (max, merge(&busiest))
+}
+
+/// Section ranges assigned to the Elves of a group, from a single line of the
+/// input.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Group {
+    /// Line of the input with the group, starting at 1.
+    line: usize,
+    ranges: Vec<SectionRange>,
+}
+
+impl Group {
+    /// Whether the range of one of the Elves contains the ranges of every
+    /// other Elf of the group. For a pair of Elves, as in the puzzle, this is
+    /// whether either range contains the other.
+    pub fn fully_overlap(&self) -> bool {
+        self.ranges
+            .iter()
+            .any(|outer| self.ranges.iter().all(|range| outer.contains_range(range)))
+    }
+
+    /// Whether the ranges of any two Elves of the group overlap.
+    pub fn partially_overlap(&self) -> bool {
+        self.overlapping_pairs() > 0
+    }
+
+    /// Number of pairs of Elves of the group whose ranges overlap.
+    pub fn overlapping_pairs(&self) -> usize {
+        self.ranges
+            .iter()
+            .enumerate()
+            .flat_map(|(i, first)| {
+                self.ranges[i + 1..]
+                    .iter()
+                    .map(move |second| (first, second))
+            })
+            .filter(|(first, second)| first.partially_overlap(second))
+            .count()
+    }
+}
+
+#[derive(Debug)]
+pub struct Day04 {
+    /// Number of Elves of each group, one group per line.
+    pub group_size: NonZeroUsize,
+}
+
+impl Default for Day04 {
+    fn default() -> Self {
+        Day04 {
+            group_size: NonZeroUsize::new(2).unwrap(),
+        }
+    }
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+    const EXAMPLE: &'static str = include_str!("../../inputs/day04_example.txt");
+    const REPORTS: &'static [&'static str] = &["groups", "uncovered", "max-elves"];
+
+    type Input = Vec<Group>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        parse_input(input, self.group_size.get())
+    }
+
+    fn part_one(&self, groups: &Self::Input) -> Result<Self::PartOne, Error> {
+        Ok(get_total_overlapping_sections(
+            groups,
+            &Group::fully_overlap,
+        ))
+    }
+
+    fn part_two(&self, groups: &Self::Input) -> Result<Self::PartTwo, Error> {
+        Ok(get_total_overlapping_sections(
+            groups,
+            &Group::partially_overlap,
+        ))
+    }
+
+    fn configure(&mut self, name: &str, value: Option<&str>) -> Result<(), Error> {
+        match name {
+            "group-size" => self.group_size = parse_value(name, value)?,
+            _ => return Err(unknown_parameter(name, &["group-size"])),
+        }
+
+        Ok(())
+    }
+
+    fn report(&self, name: &str, groups: &Self::Input) -> Result<Report, Error> {
+        let ranges: Vec<SectionRange> = groups
+            .iter()
+            .flat_map(|group| group.ranges.iter().copied())
+            .collect();
+
+        match name {
+            "groups" => {
+                let mut table = Table::new(&[
+                    "line",
+                    "ranges",
+                    "fully overlap",
+                    "overlapping pairs",
+                    "max elves",
+                ]);
+
+                for group in groups {
+                    let ranges: Vec<String> =
+                        group.ranges.iter().map(|range| range.to_string()).collect();
+                    table.push(vec![
+                        group.line.to_string(),
+                        ranges.join(","),
+                        if group.fully_overlap() { "yes" } else { "no" }.to_string(),
+                        group.overlapping_pairs().to_string(),
+                        max_coverage(&group.ranges).0.to_string(),
+                    ]);
+                }
+
+                Ok(Report::Table(table))
+            }
+            "uncovered" => {
+                let last = ranges.iter().map(SectionRange::end).max().unwrap_or(1);
+                let bounds = SectionRange::new(1, last.max(1)).unwrap();
+                let mut table = Table::new(&["start", "end", "sections"]);
+
+                for gap in uncovered(&ranges, bounds) {
+                    table.push(vec![
+                        gap.start.to_string(),
+                        gap.end.to_string(),
+                        gap.len().to_string(),
+                    ]);
+                }
+
+                Ok(Report::Table(table))
+            }
+            "max-elves" => {
+                let (elves, busiest) = max_coverage(&ranges);
+                let mut table = Table::new(&["start", "end", "elves"]);
+
+                for range in busiest {
+                    table.push(vec![
+                        range.start.to_string(),
+                        range.end.to_string(),
+                        elves.to_string(),
+                    ]);
+                }
+
+                Ok(Report::Table(table))
+            }
+            _ => Err(unknown_report(name, Self::REPORTS)),
+        }
+    }
+}
+
+fn get_total_overlapping_sections(
+    groups: &[Group],
+    is_overlapping_fn: &dyn Fn(&Group) -> bool,
+) -> u32 {
+    let mut total = 0;
+
+    for group in groups {
+        if is_overlapping_fn(group) {
+            total += 1;
+        }
+    }
+
+    total
+}
+
+/// Parses a group of `group_size` section ranges, separated by commas, from
+/// each line that is not blank.
+fn parse_input(input: &str, group_size: usize) -> Result<Vec<Group>, Error> {
+    let mut groups = vec![];
+
+    for (i, line) in input.lines().enumerate() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut ranges = vec![];
+        let mut column = 1;
+        for token in line.split(',') {
+            ranges.push(parse_range(token).map_err(|err| err.at(i + 1, column))?);
+            column += token.len() + 1;
+        }
+
+        if ranges.len() != group_size {
+            return Err(Error::parse(format!(
+                "expected {} section ranges but found {}",
+                group_size,
+                ranges.len()
+            ))
+            .at(i + 1, 1));
+        }
+
+        groups.push(Group {
+            line: i + 1,
+            ranges,
+        });
+    }
+
+    Ok(groups)
+}
+
+fn parse_range(token: &str) -> Result<SectionRange, Error> {
+    let numbers: Vec<u32> = token
+        .split('-')
+        .map(|t| {
+            t.parse::<u32>().map_err(|err| {
+                Error::parse(format!("invalid section range `{}`", token)).with_source(err)
+            })
+        })
+        .collect::<Result<Vec<u32>, Error>>()?;
+    let missing_parameter = || {
+        Error::parse(format!(
+            "section range `{}` must have a start and an end",
+            token
+        ))
+    };
+    let [start, end] = numbers[..] else {
+        return Err(missing_parameter());
+    };
+
+    SectionRange::new(start, end)
+        .ok_or_else(|| Error::parse(format!("section range `{}` ends before it starts", token)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Position;
+
+    #[test]
+    fn parse_input_with_example_input() {
+        let input = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8";
+        let expected = example_groups();
+        let result = parse_input(input, 2).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_input_with_wrong_number_of_ranges() {
+        let test_cases = [
+            (
+                "2-4,6-8\n2-3,4-5,7-8\n5-7,7-9",
+                2,
+                "expected 2 section ranges but found 3",
+            ),
+            (
+                "2-4,6-8\n2-3\n5-7,7-9",
+                2,
+                "expected 2 section ranges but found 1",
+            ),
+            (
+                "2-4,6-8,1-1\n2-3,4-5",
+                3,
+                "expected 3 section ranges but found 2",
+            ),
+        ];
+
+        for (input, group_size, message) in test_cases {
+            let result = parse_input(input, group_size).unwrap_err();
+
+            assert_eq!(result.position(), Some(Position { line: 2, column: 1 }));
+            assert_eq!(result.message(), message);
+        }
+    }
+
+    #[test]
+    fn parse_input_with_missing_comma() {
+        let input = "2-4,6-8\n2-3 4-5";
+        let result = parse_input(input, 2).unwrap_err();
+
+        assert_eq!(result.position(), Some(Position { line: 2, column: 1 }));
+        assert!(parse_input("1-2-3,4-5", 2).is_err());
+    }
+
+    #[test]
+    fn groups_of_three_elves() {
+        let groups = parse_input("1-9,2-3,5-6\n1-5,2-9,3-4\n1-2,4-5,7-8\n1-1,1-1,1-1", 3).unwrap();
+        let result: Vec<(bool, usize)> = groups
+            .iter()
+            .map(|group| (group.fully_overlap(), group.overlapping_pairs()))
+            .collect();
+
+        assert_eq!(result, vec![(true, 2), (false, 3), (false, 0), (true, 3)]);
+        assert_eq!(
+            get_total_overlapping_sections(&groups, &Group::partially_overlap),
+            3
+        );
+    }
+
+    #[test]
+    fn parse_input_with_empty_input() {
+        let input = "\n\n\n";
+        let result = parse_input(input, 2).unwrap();
+
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn parse_input_with_invalid_range() {
+        let input = "2-4,6-8\n2-3,4-x";
+        let result = parse_input(input, 2).unwrap_err();
+
+        assert_eq!(result.position(), Some(Position { line: 2, column: 5 }));
+    }
+
+    #[test]
+    fn fully_overlap_with_example() {
+        let test_cases = [
+            ((2, 4), (6, 8), false),
+            ((2, 3), (4, 5), false),
+            ((5, 7), (7, 9), false),
+            ((2, 8), (3, 7), true),
+            ((6, 6), (4, 6), true),
+            ((2, 6), (4, 8), false),
+        ];
+
+        for ((x1, y1), (x2, y2), expected) in test_cases {
+            let range_1 = SectionRange { start: x1, end: y1 };
+            let range_2 = SectionRange { start: x2, end: y2 };
+            let result = range_1.fully_overlap(&range_2);
+
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn partially_overlap_with_example() {
+        let test_cases = [
+            ((2, 4), (6, 8), false),
+            ((6, 8), (2, 4), false),
+            ((2, 3), (4, 5), false),
+            ((5, 7), (7, 9), true),
+            ((2, 8), (3, 7), true),
+            ((6, 6), (4, 6), true),
+            ((2, 6), (4, 8), true),
+        ];
+
+        for test_case in test_cases {
+            let ((x1, y1), (x2, y2), expected) = test_case;
+            let range_1 = SectionRange { start: x1, end: y1 };
+            let range_2 = SectionRange { start: x2, end: y2 };
+            let result = range_1.partially_overlap(&range_2);
+
+            assert_eq!(
+                result, expected,
+                "expected {}, got {} for test case {:?}",
+                expected, result, test_case
+            );
+        }
+    }
+
+    #[test]
+    fn get_total_of_fully_overlapping_sections_with_example_input() {
+        let input = example_groups();
+        let result = get_total_overlapping_sections(&input, &Group::fully_overlap);
+
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn get_total_of_partially_overlapping_sections_with_example_input() {
+        let input = example_groups();
+        let result = get_total_overlapping_sections(&input, &Group::partially_overlap);
+
+        assert_eq!(result, 4);
+    }
+
+    fn range(start: u32, end: u32) -> SectionRange {
+        SectionRange::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn parse_input_with_reversed_range() {
+        let input = "2-4,8-6";
+        let result = parse_input(input, 2).unwrap_err();
+
+        assert_eq!(result.position(), Some(Position { line: 1, column: 5 }));
+        assert_eq!(
+            result.message(),
+            "section range `8-6` ends before it starts"
+        );
+    }
+
+    #[test]
+    fn len_and_contains() {
+        let value = range(3, 7);
+
+        assert_eq!(value.len(), 5);
+        assert_eq!(range(6, 6).len(), 1);
+        assert_eq!(range(0, u32::MAX).len(), 1 << 32);
+        assert!(value.contains(3) && value.contains(7));
+        assert!(!value.contains(2) && !value.contains(8));
+        assert!(value.contains_range(&range(4, 7)));
+        assert!(!value.contains_range(&range(4, 8)));
+    }
+
+    #[test]
+    fn intersection_union_and_difference() {
+        let test_cases = [
+            ((2, 6), (4, 8), Some((4, 6)), vec![(2, 8)], vec![(2, 3)]),
+            ((2, 4), (6, 8), None, vec![(2, 4), (6, 8)], vec![(2, 4)]),
+            ((2, 4), (5, 8), None, vec![(2, 8)], vec![(2, 4)]),
+            (
+                (2, 8),
+                (3, 7),
+                Some((3, 7)),
+                vec![(2, 8)],
+                vec![(2, 2), (8, 8)],
+            ),
+            ((3, 7), (2, 8), Some((3, 7)), vec![(2, 8)], vec![]),
+            ((6, 6), (4, 6), Some((6, 6)), vec![(4, 6)], vec![]),
+        ];
+
+        for ((x1, y1), (x2, y2), intersection, union, difference) in test_cases {
+            let (range_1, range_2) = (range(x1, y1), range(x2, y2));
+            let to_ranges = |ranges: Vec<(u32, u32)>| -> Vec<SectionRange> {
+                ranges.into_iter().map(|(x, y)| range(x, y)).collect()
+            };
+
+            assert_eq!(
+                range_1.intersection(&range_2),
+                intersection.map(|(x, y)| range(x, y))
+            );
+            assert_eq!(range_1.union(&range_2), to_ranges(union));
+            assert_eq!(range_1.difference(&range_2), to_ranges(difference));
+        }
+    }
+
+    #[test]
+    fn difference_at_the_limits() {
+        assert_eq!(range(0, 5).difference(&range(0, 2)), vec![range(3, 5)]);
+        assert_eq!(
+            range(0, u32::MAX).difference(&range(1, u32::MAX - 1)),
+            vec![range(0, 0), range(u32::MAX, u32::MAX)]
+        );
+    }
+
+    #[test]
+    fn merge_with_example_input() {
+        let result = merge(&parsed_example_input());
+
+        assert_eq!(result, vec![range(2, 9)]);
+        assert_eq!(
+            merge(&[range(10, 12), range(1, 3), range(2, 5), range(7, 7)]),
+            vec![range(1, 5), range(7, 7), range(10, 12)]
+        );
+    }
+
+    #[test]
+    fn uncovered_with_gaps() {
+        let ranges = [range(3, 4), range(8, 9), range(4, 5)];
+        let result = uncovered(&ranges, range(1, 12));
+
+        assert_eq!(result, vec![range(1, 2), range(6, 7), range(10, 12)]);
+        assert!(uncovered(&parsed_example_input(), range(2, 9)).is_empty());
+    }
+
+    #[test]
+    fn uncovered_with_ranges_past_bounds() {
+        let ranges = [range(0, 2), range(6, 7), range(9, u32::MAX)];
+
+        assert_eq!(
+            uncovered(&ranges, range(1, 8)),
+            vec![range(3, 5), range(8, 8)]
+        );
+        assert_eq!(uncovered(&ranges, range(3, 4)), vec![range(3, 4)]);
+        assert!(uncovered(&ranges, range(10, u32::MAX)).is_empty());
+        assert_eq!(
+            uncovered(&[range(0, 5)], range(0, u32::MAX)),
+            vec![range(6, u32::MAX)]
+        );
+    }
+
+    #[test]
+    fn max_coverage_with_example_input() {
+        let result = max_coverage(&parsed_example_input());
+
+        assert_eq!(result, (8, vec![range(6, 6)]));
+        assert_eq!(max_coverage(&[]), (0, vec![]));
+    }
+
+    #[test]
+    fn coverage_counts_every_range() {
+        let ranges = [range(1, 4), range(3, 6), range(8, 8)];
+        let result = coverage(&ranges);
+        let expected = vec![
+            (range(1, 2), 1),
+            (range(3, 4), 2),
+            (range(5, 6), 1),
+            (range(8, 8), 1),
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    fn example_groups() -> Vec<Group> {
+        parsed_example_input()
+            .chunks(2)
+            .enumerate()
+            .map(|(i, ranges)| Group {
+                line: i + 1,
+                ranges: ranges.to_vec(),
+            })
+            .collect()
+    }
+
+    fn parsed_example_input() -> Vec<SectionRange> {
+        vec![
+            SectionRange { start: 2, end: 4 },
+            SectionRange { start: 6, end: 8 },
+            SectionRange { start: 2, end: 3 },
+            SectionRange { start: 4, end: 5 },
+            SectionRange { start: 5, end: 7 },
+            SectionRange { start: 7, end: 9 },
+            SectionRange { start: 2, end: 8 },
+            SectionRange { start: 3, end: 7 },
+            SectionRange { start: 6, end: 6 },
+            SectionRange { start: 4, end: 6 },
+            SectionRange { start: 2, end: 6 },
+            SectionRange { start: 4, end: 8 },
+        ]
+    }
+}
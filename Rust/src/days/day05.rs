@@ -0,0 +1,1277 @@
+use std::{fmt, num::NonZeroUsize, ops::Range};
+
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::{
+    config::{parse_value, unknown_parameter},
+    report::{unknown_report, Report, Table},
+    Error, ErrorKind, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    amount: usize,
+    source: usize,
+    destination: usize,
+}
+
+/// A crate, named by what is written between its brackets in the diagram.
+pub type Crate = String;
+
+pub type Stack = Vec<Crate>;
+
+#[derive(Debug)]
+pub struct Day05 {
+    pub part_one_crane: Box<dyn CrateMover>,
+    pub part_two_crane: Box<dyn CrateMover>,
+    /// Cranes compared by the `cranes` report, with their names.
+    pub cranes: Vec<(String, Box<dyn CrateMover>)>,
+    /// Number of moves between the frames of the `frames` report.
+    pub frame_every: NonZeroUsize,
+}
+
+impl Default for Day05 {
+    fn default() -> Self {
+        Day05 {
+            part_one_crane: Box::new(CrateMover9000),
+            part_two_crane: Box::new(CrateMover9001),
+            cranes: vec![
+                ("9000".to_string(), Box::new(CrateMover9000)),
+                ("9001".to_string(), Box::new(CrateMover9001)),
+            ],
+            frame_every: NonZeroUsize::new(1).unwrap(),
+        }
+    }
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Supply Stacks";
+    const EXAMPLE: &'static str = include_str!("../../inputs/day05_example.txt");
+    const REPORTS: &'static [&'static str] = &["dry-run", "cranes", "frames"];
+
+    type Input = (Vec<Stack>, Vec<Move>);
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, (stacks, instructions): &Self::Input) -> Result<Self::PartOne, Error> {
+        let stacks = rearrange(stacks, instructions, self.part_one_crane.as_ref())?;
+
+        Ok(get_top_crates_message(stacks))
+    }
+
+    fn part_two(&self, (stacks, instructions): &Self::Input) -> Result<Self::PartTwo, Error> {
+        let stacks = rearrange(stacks, instructions, self.part_two_crane.as_ref())?;
+
+        Ok(get_top_crates_message(stacks))
+    }
+
+    fn configure(&mut self, name: &str, value: Option<&str>) -> Result<(), Error> {
+        match name {
+            "part-one-crane" => self.part_one_crane = crane(&parse_value::<String>(name, value)?)?,
+            "part-two-crane" => self.part_two_crane = crane(&parse_value::<String>(name, value)?)?,
+            "cranes" => {
+                self.cranes = parse_value::<String>(name, value)?
+                    .split(',')
+                    .map(|name| Ok((name.to_string(), crane(name)?)))
+                    .collect::<Result<_, Error>>()?
+            }
+            "frame-every" => self.frame_every = parse_value(name, value)?,
+            _ => {
+                return Err(unknown_parameter(
+                    name,
+                    &["part-one-crane", "part-two-crane", "cranes", "frame-every"],
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
+    fn report(&self, name: &str, (stacks, instructions): &Self::Input) -> Result<Report, Error> {
+        match name {
+            "dry-run" => {
+                let mut table = Table::new(&["instruction", "move", "problem", "shortfall"]);
+
+                for invalid in dry_run(stacks, instructions, self.part_one_crane.as_ref()) {
+                    let shortfall = match invalid.error {
+                        MoveError::NotEnoughCrates { shortfall, .. } => shortfall.to_string(),
+                        _ => String::new(),
+                    };
+                    table.push(vec![
+                        invalid.instruction.to_string(),
+                        instructions[invalid.instruction - 1].to_string(),
+                        invalid.error.to_string(),
+                        shortfall,
+                    ]);
+                }
+
+                Ok(Report::Table(table))
+            }
+            "cranes" => {
+                let mut table = Table::new(&["crane", "message"]);
+
+                for (name, crane) in &self.cranes {
+                    let message = match rearrange(stacks, instructions, crane.as_ref()) {
+                        Ok(stacks) => get_top_crates_message(stacks),
+                        Err(invalid) => Error::from(invalid).to_string(),
+                    };
+                    table.push(vec![name.clone(), message]);
+                }
+
+                Ok(Report::Table(table))
+            }
+            "frames" => Ok(Report::Frames(frames(
+                stacks,
+                instructions,
+                self.part_one_crane.as_ref(),
+                self.frame_every.get(),
+            ))),
+            _ => Err(unknown_report(name, Self::REPORTS)),
+        }
+    }
+}
+
+/// Why a move cannot be made.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoveError {
+    /// The move takes crates from or to a stack that does not exist.
+    UnknownStack { stack: usize },
+    /// The source stack has `shortfall` crates less than the move takes.
+    NotEnoughCrates {
+        stack: usize,
+        crates: usize,
+        shortfall: usize,
+    },
+    /// The crane cannot reach the source stack.
+    Unreachable { stack: usize },
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveError::UnknownStack { stack } => write!(f, "there is no stack {}", stack),
+            MoveError::NotEnoughCrates {
+                stack,
+                crates,
+                shortfall,
+            } => write!(
+                f,
+                "stack {} has {} crates, {} less than the move takes",
+                stack, crates, shortfall
+            ),
+            MoveError::Unreachable { stack } => {
+                write!(f, "the crane cannot reach stack {}", stack)
+            }
+        }
+    }
+}
+
+/// A move of the rearrangement procedure that cannot be made, with the number
+/// of its instruction (starting at 1).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidMove {
+    pub instruction: usize,
+    pub error: MoveError,
+}
+
+impl From<InvalidMove> for Error {
+    fn from(invalid: InvalidMove) -> Error {
+        Error::solve(format!(
+            "cannot make move {}: {}",
+            invalid.instruction, invalid.error
+        ))
+    }
+}
+
+impl Move {
+    /// Checks that both stacks of the move exist and that the source stack
+    /// has enough crates for it.
+    fn check(&self, stacks: &[Stack]) -> Result<(), MoveError> {
+        let crates = |stack: usize| {
+            stack
+                .checked_sub(1)
+                .and_then(|index| stacks.get(index))
+                .map(Vec::len)
+                .ok_or(MoveError::UnknownStack { stack })
+        };
+        let available = crates(self.source)?;
+        crates(self.destination)?;
+
+        if available < self.amount {
+            Err(MoveError::NotEnoughCrates {
+                stack: self.source,
+                crates: available,
+                shortfall: self.amount - available,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.amount, self.source, self.destination
+        )
+    }
+}
+
+/// Crates taken at once from the top of the `source` stack and put on top of
+/// the `destination` stack, in reverse order when `reversed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lift {
+    pub amount: usize,
+    pub source: usize,
+    pub destination: usize,
+    pub reversed: bool,
+}
+
+impl Lift {
+    /// Moves the crates, taking time proportional to their amount.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a stack does not exist or the source has too few crates,
+    /// which [`Move::check`] rules out.
+    fn apply(&self, stacks: &mut [Stack]) {
+        let (source, destination) = (self.source - 1, self.destination - 1);
+
+        if source == destination {
+            if self.reversed {
+                let stack = &mut stacks[source];
+                let start = stack.len() - self.amount;
+                stack[start..].reverse();
+            }
+            return;
+        }
+
+        let (source, destination) = if source < destination {
+            let (left, right) = stacks.split_at_mut(destination);
+            (&mut left[source], &mut right[0])
+        } else {
+            let (left, right) = stacks.split_at_mut(source);
+            (&mut right[0], &mut left[destination])
+        };
+        let crates = source.drain(source.len() - self.amount..);
+
+        if self.reversed {
+            destination.extend(crates.rev());
+        } else {
+            destination.extend(crates);
+        }
+    }
+
+    /// The lift that puts the crates back where they were.
+    pub fn inverse(&self) -> Lift {
+        Lift {
+            source: self.destination,
+            destination: self.source,
+            ..*self
+        }
+    }
+}
+
+/// A crane that moves crates between the stacks.
+pub trait CrateMover: fmt::Debug {
+    /// Lifts the crane makes for the move, after checking it can be made.
+    fn lifts(&self, stacks: &[Stack], instruction: &Move) -> Result<Vec<Lift>, MoveError>;
+
+    /// Makes the move and returns its lifts, leaving the stacks untouched
+    /// when it cannot be made.
+    fn apply(&self, stacks: &mut [Stack], instruction: &Move) -> Result<Vec<Lift>, MoveError> {
+        let lifts = self.lifts(stacks, instruction)?;
+
+        for lift in &lifts {
+            lift.apply(stacks);
+        }
+
+        Ok(lifts)
+    }
+}
+
+/// Moves one crate at a time, so the moved crates end up in reverse order.
+#[derive(Debug)]
+pub struct CrateMover9000;
+
+impl CrateMover for CrateMover9000 {
+    fn lifts(&self, stacks: &[Stack], instruction: &Move) -> Result<Vec<Lift>, MoveError> {
+        instruction.check(stacks)?;
+
+        Ok(vec![Lift {
+            amount: instruction.amount,
+            source: instruction.source,
+            destination: instruction.destination,
+            reversed: true,
+        }])
+    }
+}
+
+/// Moves all the crates at once, so they keep their order.
+#[derive(Debug)]
+pub struct CrateMover9001;
+
+impl CrateMover for CrateMover9001 {
+    fn lifts(&self, stacks: &[Stack], instruction: &Move) -> Result<Vec<Lift>, MoveError> {
+        instruction.check(stacks)?;
+
+        Ok(vec![Lift {
+            amount: instruction.amount,
+            source: instruction.source,
+            destination: instruction.destination,
+            reversed: false,
+        }])
+    }
+}
+
+/// A CrateMover 9001 that lifts at most `capacity` crates at once, so larger
+/// moves are split in several lifts from the top of the source stack.
+#[derive(Debug)]
+pub struct LimitedCrateMover {
+    pub capacity: NonZeroUsize,
+}
+
+impl CrateMover for LimitedCrateMover {
+    fn lifts(&self, stacks: &[Stack], instruction: &Move) -> Result<Vec<Lift>, MoveError> {
+        instruction.check(stacks)?;
+
+        let capacity = self.capacity.get();
+        let lifts = (0..instruction.amount)
+            .step_by(capacity)
+            .map(|lifted| Lift {
+                amount: capacity.min(instruction.amount - lifted),
+                source: instruction.source,
+                destination: instruction.destination,
+                reversed: false,
+            })
+            .collect();
+
+        Ok(lifts)
+    }
+}
+
+/// A crane that can only take crates from the two tallest stacks, or from
+/// those tied with them, and otherwise moves them like `crane`.
+#[derive(Debug)]
+pub struct TopTwoStacks {
+    pub crane: Box<dyn CrateMover>,
+}
+
+impl CrateMover for TopTwoStacks {
+    fn lifts(&self, stacks: &[Stack], instruction: &Move) -> Result<Vec<Lift>, MoveError> {
+        instruction.check(stacks)?;
+
+        let height = stacks[instruction.source - 1].len();
+        let taller = stacks.iter().filter(|stack| stack.len() > height).count();
+
+        if taller >= 2 {
+            return Err(MoveError::Unreachable {
+                stack: instruction.source,
+            });
+        }
+
+        self.crane.lifts(stacks, instruction)
+    }
+}
+
+/// Stacks being rearranged, with a log of the lifts of every move made so the
+/// moves can be undone.
+#[derive(Debug, Clone, Default)]
+pub struct Yard {
+    stacks: Vec<Stack>,
+    log: Vec<Vec<Lift>>,
+}
+
+impl Yard {
+    pub fn new(stacks: Vec<Stack>) -> Yard {
+        Yard {
+            stacks,
+            log: vec![],
+        }
+    }
+
+    pub fn stacks(&self) -> &[Stack] {
+        &self.stacks
+    }
+
+    /// Number of moves made that can be undone.
+    pub fn moves(&self) -> usize {
+        self.log.len()
+    }
+
+    /// Makes the move with the `crane`, leaving the stacks untouched when it
+    /// cannot be made.
+    pub fn apply(&mut self, crane: &dyn CrateMover, instruction: &Move) -> Result<(), MoveError> {
+        let lifts = crane.apply(&mut self.stacks, instruction)?;
+        self.log.push(lifts);
+
+        Ok(())
+    }
+
+    /// Undoes the last move made, returning `false` when there is none.
+    pub fn undo(&mut self) -> bool {
+        let Some(lifts) = self.log.pop() else {
+            return false;
+        };
+
+        for lift in lifts.iter().rev() {
+            lift.inverse().apply(&mut self.stacks);
+        }
+
+        true
+    }
+
+    /// Undoes the last `moves` moves, or every move if there are fewer.
+    pub fn rewind(&mut self, moves: usize) {
+        for _ in 0..moves {
+            if !self.undo() {
+                break;
+            }
+        }
+    }
+}
+
+/// Builds the crane with the given `name`: `9000`, `9001`, `limited-<N>` for
+/// a CrateMover 9001 that lifts at most N crates at once, or `top-two-<name>`
+/// for a crane that only takes crates from the two tallest stacks.
+pub fn crane(name: &str) -> Result<Box<dyn CrateMover>, Error> {
+    let unknown_crane = || {
+        Error::new(
+            ErrorKind::Config,
+            format!(
+                "unknown crane `{}`, expected `9000`, `9001`, `limited-<N>` or `top-two-<crane>`",
+                name
+            ),
+        )
+    };
+
+    if let Some(crane_name) = name.strip_prefix("top-two-") {
+        Ok(Box::new(TopTwoStacks {
+            crane: crane(crane_name)?,
+        }))
+    } else if let Some(capacity) = name.strip_prefix("limited-") {
+        let capacity = capacity.parse().map_err(|_| unknown_crane())?;
+
+        Ok(Box::new(LimitedCrateMover { capacity }))
+    } else {
+        match name {
+            "9000" => Ok(Box::new(CrateMover9000)),
+            "9001" => Ok(Box::new(CrateMover9001)),
+            _ => Err(unknown_crane()),
+        }
+    }
+}
+
+/// Makes every move on a copy of the `stacks`, stopping at the first one that
+/// cannot be made.
+fn rearrange(
+    stacks: &[Stack],
+    moves: &[Move],
+    crane: &dyn CrateMover,
+) -> Result<Vec<Stack>, InvalidMove> {
+    let mut stacks = stacks.to_vec();
+
+    for (index, instruction) in moves.iter().enumerate() {
+        crane
+            .apply(&mut stacks, instruction)
+            .map_err(|error| InvalidMove {
+                instruction: index + 1,
+                error,
+            })?;
+    }
+
+    Ok(stacks)
+}
+
+/// Finds every move that cannot be made. Invalid moves are skipped, and the
+/// following moves are checked against the stacks as if they never happened.
+fn dry_run(stacks: &[Stack], moves: &[Move], crane: &dyn CrateMover) -> Vec<InvalidMove> {
+    let mut stacks = stacks.to_vec();
+
+    moves
+        .iter()
+        .enumerate()
+        .filter_map(|(index, instruction)| {
+            crane
+                .apply(&mut stacks, instruction)
+                .err()
+                .map(|error| InvalidMove {
+                    instruction: index + 1,
+                    error,
+                })
+        })
+        .collect()
+}
+
+/// Draws the stacks before the first move, after every `every` moves and after
+/// the last one, with a caption of the last move made. Stops with a frame of
+/// the first move that cannot be made.
+fn frames(stacks: &[Stack], moves: &[Move], crane: &dyn CrateMover, every: usize) -> Vec<String> {
+    let mut stacks = stacks.to_vec();
+    let mut frames = vec![format!("Starting stacks\n{}", render(&stacks))];
+
+    for (index, instruction) in moves.iter().enumerate() {
+        let number = index + 1;
+
+        if let Err(error) = crane.apply(&mut stacks, instruction) {
+            frames.push(format!(
+                "Move {} of {} ({}) cannot be made: {}\n{}",
+                number,
+                moves.len(),
+                instruction,
+                error,
+                render(&stacks)
+            ));
+            break;
+        }
+
+        if number % every == 0 || number == moves.len() {
+            frames.push(format!(
+                "After move {} of {} ({})\n{}",
+                number,
+                moves.len(),
+                instruction,
+                render(&stacks)
+            ));
+        }
+    }
+
+    frames
+}
+
+/// Draws the `stacks` like the diagram at the start of the puzzle input. Every
+/// stack takes as many columns as its widest crate or number.
+pub fn render(stacks: &[Stack]) -> String {
+    let width = stacks
+        .iter()
+        .flatten()
+        .map(|name| name.chars().count() + 2)
+        .chain([3, stacks.len().to_string().len()])
+        .max()
+        .unwrap();
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut lines = vec![];
+
+    for level in (0..height).rev() {
+        let line = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(name) => format!("{:^width$}", format!("[{}]", name)),
+                None => " ".repeat(width),
+            })
+            .join(" ");
+        lines.push(line);
+    }
+
+    lines.push(
+        (1..=stacks.len())
+            .map(|number| format!("{:^width$}", number))
+            .join(" "),
+    );
+
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+fn get_top_crates_message(stacks: Vec<Stack>) -> String {
+    stacks.iter().filter_map(|s| s.last()).join("")
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Stack>, Vec<Move>), Error> {
+    let lines: Vec<&str> = input.lines().collect();
+    let blank_line = lines
+        .iter()
+        .position(|line| line.trim_end_matches('\r').is_empty())
+        .ok_or_else(|| Error::parse("missing rearrangement procedure"))?;
+    let stacks = parse_diagram(&lines[..blank_line].join("\n"))?;
+
+    let regex = r"^move (?P<amount>\d+) from (?P<source>\d+) to (?P<destination>\d+)$";
+    let regex = Regex::new(regex).unwrap();
+    let first_move_line = blank_line + 2;
+    let mut moves = vec![];
+
+    for (index, line) in lines[blank_line + 1..].iter().enumerate() {
+        let line_number = first_move_line + index;
+        let line = line.trim_end();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let captures = regex.captures(line).ok_or_else(|| {
+            Error::parse(format!(
+                "invalid move `{}`, expected `move <amount> from <stack> to <stack>`",
+                line
+            ))
+            .at(line_number, 1)
+        })?;
+        let parse_number = |name: &str| {
+            let m = &captures[name];
+
+            m.parse::<usize>().map_err(|err| {
+                let column = captures.name(name).unwrap().start() + 1;
+
+                Error::parse(format!("invalid {} `{}`", name, m))
+                    .at(line_number, column)
+                    .with_source(err)
+            })
+        };
+
+        moves.push(Move {
+            amount: parse_number("amount")?,
+            source: parse_number("source")?,
+            destination: parse_number("destination")?,
+        });
+    }
+
+    Ok((stacks, moves))
+}
+
+/// Parses the drawing of the stacks. The stacks are numbered in its last line,
+/// and each crate belongs to the stack whose number is right below it, so
+/// stacks can have any number of digits and crates any number of letters.
+fn parse_diagram(diagram: &str) -> Result<Vec<Stack>, Error> {
+    let lines: Vec<&str> = diagram.lines().collect();
+    let (numbers_line, crate_lines) = lines
+        .split_last()
+        .ok_or_else(|| Error::parse("missing crate diagram").at(1, 1))?;
+    let numbers = words(numbers_line);
+
+    if numbers.is_empty() {
+        return Err(Error::parse("missing stack numbers").at(lines.len(), 1));
+    }
+
+    for (index, (columns, number)) in numbers.iter().enumerate() {
+        if number.parse::<usize>() != Ok(index + 1) {
+            return Err(Error::parse(format!(
+                "expected stack `{}` but found `{}`",
+                index + 1,
+                number
+            ))
+            .at(lines.len(), columns.start + 1));
+        }
+    }
+
+    let mut stacks: Vec<Stack> = vec![vec![]; numbers.len()];
+
+    for (height, (index, line)) in crate_lines.iter().enumerate().rev().enumerate() {
+        for (columns, word) in words(line) {
+            let error = |message: String| Error::parse(message).at(index + 1, columns.start + 1);
+            let name = word
+                .strip_prefix('[')
+                .and_then(|word| word.strip_suffix(']'))
+                .filter(|name| !name.is_empty() && !name.contains(['[', ']']))
+                .ok_or_else(|| error(format!("invalid crate `{}`", word)))?;
+            let below: Vec<usize> = numbers
+                .iter()
+                .positions(|(number_columns, _)| {
+                    number_columns.start < columns.end && columns.start < number_columns.end
+                })
+                .collect();
+            let [stack] = below[..] else {
+                return Err(error(format!(
+                    "crate `{}` is not aligned with the number of a single stack",
+                    word
+                )));
+            };
+
+            if stacks[stack].len() < height {
+                return Err(error(format!(
+                    "crate `{}` is floating above stack {}",
+                    word,
+                    stack + 1
+                )));
+            }
+            if stacks[stack].len() > height {
+                return Err(error(format!(
+                    "crate `{}` is next to another crate of stack {}",
+                    word,
+                    stack + 1
+                )));
+            }
+
+            stacks[stack].push(name.to_string());
+        }
+    }
+
+    Ok(stacks)
+}
+
+/// Words of the `line` separated by whitespace, with the columns they take.
+fn words(line: &str) -> Vec<(Range<usize>, &str)> {
+    let mut words = vec![];
+    let mut start = None;
+
+    for (column, (offset, c)) in line.char_indices().chain([(line.len(), ' ')]).enumerate() {
+        match (start, c.is_whitespace()) {
+            (None, false) => start = Some((column, offset)),
+            (Some((start_column, start_offset)), true) => {
+                words.push((start_column..column, &line[start_offset..offset]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Position;
+
+    /// Stacks with a crate for each letter, from the bottom to the top.
+    fn stacks_from(letters: &[&str]) -> Vec<Stack> {
+        letters
+            .iter()
+            .map(|letters| letters.chars().map(String::from).collect())
+            .collect()
+    }
+
+    #[test]
+    fn parse_input_with_example_input() {
+        let input = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+        let (stacks, moves) = parse_input(input).unwrap();
+        let expected_stacks = stacks_from(&["ZN", "MCD", "P"]);
+        let expected_moves = vec![
+            Move {
+                amount: 1,
+                source: 2,
+                destination: 1,
+            },
+            Move {
+                amount: 3,
+                source: 1,
+                destination: 3,
+            },
+            Move {
+                amount: 2,
+                source: 2,
+                destination: 1,
+            },
+            Move {
+                amount: 1,
+                source: 1,
+                destination: 2,
+            },
+        ];
+
+        assert_eq!(stacks, expected_stacks);
+        assert_eq!(moves, expected_moves);
+    }
+
+    #[test]
+    fn parse_input_with_invalid_move() {
+        let input = "[Z] [M]
+ 1   2 
+
+move 1 from 2 to 1
+move 99999999999999999999 from 1 to 2";
+        let result = parse_input(input).unwrap_err();
+
+        assert_eq!(result.position(), Some(Position { line: 5, column: 6 }));
+    }
+
+    #[test]
+    fn parse_input_with_crlf_line_endings() {
+        let input = [
+            "    [D]    ",
+            "[N] [C]    ",
+            "[Z] [M] [P]",
+            " 1   2   3 ",
+            "",
+            "move 1 from 2 to 1",
+            "move x from 1 to 3",
+        ]
+        .join("\r\n");
+        let result = parse_input(&input).unwrap_err();
+
+        assert_eq!(result.position(), Some(Position { line: 7, column: 1 }));
+
+        let input = input.replace('x', "3");
+        let (stacks, moves) = parse_input(&input).unwrap();
+
+        assert_eq!(stacks, stacks_from(&["ZN", "MCD", "P"]));
+        assert_eq!(moves.len(), 2);
+    }
+
+    #[test]
+    fn parse_diagram_with_trimmed_lines() {
+        let input = "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3";
+        let result = parse_diagram(input).unwrap();
+        let expected = stacks_from(&["ZN", "MCD", "P"]);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_diagram_with_ten_stacks() {
+        let input = [
+            "                                    [J]",
+            "[A] [B] [C] [D] [E] [F] [G] [H] [I] [K]",
+            " 1   2   3   4   5   6   7   8   9   10",
+        ]
+        .join("\n");
+        let result = parse_diagram(&input).unwrap();
+        let expected = stacks_from(&["A", "B", "C", "D", "E", "F", "G", "H", "I", "KJ"]);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_diagram_with_long_crate_names() {
+        let input = "\
+[AB]
+[CD] [EF]
+  1    2";
+        let result = parse_diagram(input).unwrap();
+        let expected = vec![
+            vec!["CD".to_string(), "AB".to_string()],
+            vec!["EF".to_string()],
+        ];
+
+        assert_eq!(result, expected);
+        assert_eq!(get_top_crates_message(result), "ABEF");
+    }
+
+    #[test]
+    fn parse_diagram_with_misaligned_crate() {
+        let input = "[A]   [B]\n 1   2";
+        let result = parse_diagram(input).unwrap_err();
+
+        assert_eq!(
+            result.message(),
+            "crate `[B]` is not aligned with the number of a single stack"
+        );
+        assert_eq!(result.position(), Some(Position { line: 1, column: 7 }));
+    }
+
+    #[test]
+    fn parse_diagram_with_floating_crate() {
+        let input = "[A] [B]\n[C]\n 1   2";
+        let result = parse_diagram(input).unwrap_err();
+
+        assert_eq!(result.message(), "crate `[B]` is floating above stack 2");
+        assert_eq!(result.position(), Some(Position { line: 1, column: 5 }));
+    }
+
+    #[test]
+    fn parse_diagram_with_invalid_crate() {
+        let input = "[A] B\n 1  2";
+        let result = parse_diagram(input).unwrap_err();
+
+        assert_eq!(result.message(), "invalid crate `B`");
+        assert_eq!(result.position(), Some(Position { line: 1, column: 5 }));
+    }
+
+    #[test]
+    fn parse_diagram_with_stacks_out_of_order() {
+        let input = "[A] [B]\n 1   3";
+        let result = parse_diagram(input).unwrap_err();
+
+        assert_eq!(result.message(), "expected stack `2` but found `3`");
+        assert_eq!(result.position(), Some(Position { line: 2, column: 6 }));
+    }
+
+    #[test]
+    fn crate_mover_9000_with_first_example() {
+        let mut stacks = stacks_from(&["ZN", "MCD", "P"]);
+        let move_instruction = Move {
+            amount: 1,
+            source: 2,
+            destination: 1,
+        };
+        let expected = stacks_from(&["ZND", "MC", "P"]);
+
+        CrateMover9000
+            .apply(&mut stacks, &move_instruction)
+            .unwrap();
+
+        assert_eq!(stacks, expected);
+    }
+
+    #[test]
+    fn crate_mover_9000_with_second_example() {
+        let mut stacks = stacks_from(&["ZND", "MC", "P"]);
+        let move_instruction = Move {
+            amount: 3,
+            source: 1,
+            destination: 3,
+        };
+        let expected = stacks_from(&["", "MC", "PDNZ"]);
+
+        CrateMover9000
+            .apply(&mut stacks, &move_instruction)
+            .unwrap();
+
+        assert_eq!(stacks, expected);
+    }
+
+    #[test]
+    fn crate_mover_9001_with_first_example() {
+        let mut stacks = stacks_from(&["ZN", "MCD", "P"]);
+        let move_instruction = Move {
+            amount: 1,
+            source: 2,
+            destination: 1,
+        };
+        let expected = stacks_from(&["ZND", "MC", "P"]);
+
+        CrateMover9001
+            .apply(&mut stacks, &move_instruction)
+            .unwrap();
+
+        assert_eq!(stacks, expected);
+    }
+
+    #[test]
+    fn crate_mover_9001_with_second_example() {
+        let mut stacks = stacks_from(&["ZND", "MC", "P"]);
+        let move_instruction = Move {
+            amount: 3,
+            source: 1,
+            destination: 3,
+        };
+        let expected = stacks_from(&["", "MC", "PZND"]);
+
+        CrateMover9001
+            .apply(&mut stacks, &move_instruction)
+            .unwrap();
+
+        assert_eq!(stacks, expected);
+    }
+
+    #[test]
+    fn parse_input_with_unknown_instruction() {
+        let input = "[Z] [M]\n 1   2 \n\nmove 1 from 2 to 1\nmove one from 1 to 2\n";
+        let result = parse_input(input).unwrap_err();
+
+        assert_eq!(
+            result.message(),
+            "invalid move `move one from 1 to 2`, expected `move <amount> from <stack> to <stack>`"
+        );
+        assert_eq!(result.position(), Some(Position { line: 5, column: 1 }));
+    }
+
+    #[test]
+    fn apply_move_with_unknown_stack() {
+        let mut stacks = stacks_from(&["ZN", "MCD", "P"]);
+        let move_instruction = Move {
+            amount: 1,
+            source: 2,
+            destination: 4,
+        };
+        let result = CrateMover9000.apply(&mut stacks, &move_instruction);
+        let expected = Err(MoveError::UnknownStack { stack: 4 });
+
+        assert_eq!(result, expected);
+        assert_eq!(stacks, stacks_from(&["ZN", "MCD", "P"]));
+    }
+
+    #[test]
+    fn apply_move_with_not_enough_crates() {
+        let mut stacks = stacks_from(&["ZN", "MCD", "P"]);
+        let move_instruction = Move {
+            amount: 5,
+            source: 1,
+            destination: 3,
+        };
+        let result = CrateMover9001.apply(&mut stacks, &move_instruction);
+        let expected = Err(MoveError::NotEnoughCrates {
+            stack: 1,
+            crates: 2,
+            shortfall: 3,
+        });
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn limited_crate_mover_splits_large_moves() {
+        let mut stacks = stacks_from(&["ZN", "MCD", "P"]);
+        let crane = LimitedCrateMover {
+            capacity: NonZeroUsize::new(2).unwrap(),
+        };
+        let move_instruction = Move {
+            amount: 3,
+            source: 2,
+            destination: 1,
+        };
+        let expected = stacks_from(&["ZNCDM", "", "P"]);
+
+        crane.apply(&mut stacks, &move_instruction).unwrap();
+
+        assert_eq!(stacks, expected);
+    }
+
+    #[test]
+    fn top_two_stacks_only_reaches_tallest_stacks() {
+        let mut stacks = stacks_from(&["ZN", "MCD", "P"]);
+        let crane = TopTwoStacks {
+            crane: Box::new(CrateMover9000),
+        };
+        let unreachable = Move {
+            amount: 1,
+            source: 3,
+            destination: 1,
+        };
+        let reachable = Move {
+            amount: 1,
+            source: 1,
+            destination: 3,
+        };
+        let expected = stacks_from(&["Z", "MCD", "PN"]);
+
+        assert_eq!(
+            crane.apply(&mut stacks, &unreachable),
+            Err(MoveError::Unreachable { stack: 3 })
+        );
+        crane.apply(&mut stacks, &reachable).unwrap();
+        assert_eq!(stacks, expected);
+    }
+
+    #[test]
+    fn crane_with_unknown_name() {
+        let result = crane("limited-0").unwrap_err();
+
+        assert_eq!(result.kind(), ErrorKind::Config);
+        assert_eq!(
+            result.message(),
+            "unknown crane `limited-0`, expected `9000`, `9001`, `limited-<N>` or `top-two-<crane>`"
+        );
+    }
+
+    #[test]
+    fn cranes_report_with_example_input() {
+        let mut day = Day05::default();
+        day.configure("cranes", Some("9000,9001,limited-2,top-two-9001"))
+            .unwrap();
+        let input = day.parse(Day05::EXAMPLE).unwrap();
+        let result = day.report("cranes", &input).unwrap().to_string();
+        let expected = "\
+crane         message
+9000          CMZ
+9001          MCD
+limited-2     MCZ
+top-two-9001  MCD
+";
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn render_with_example_input() {
+        let (stacks, _) = parse_input(Day05::EXAMPLE).unwrap();
+        let result = render(&stacks);
+        let expected = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n";
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn render_can_be_parsed_back() {
+        let mut stacks = stacks_from(&["AB", "", "C", "D", "E", "F", "G", "H", "I", "JK"]);
+        stacks[2].push("LONG".to_string());
+        let result = parse_diagram(render(&stacks).trim_end_matches('\n')).unwrap();
+
+        assert_eq!(result, stacks);
+    }
+
+    #[test]
+    fn frames_with_example_input() {
+        let (stacks, moves) = parse_input(Day05::EXAMPLE).unwrap();
+        let result = frames(&stacks, &moves, &CrateMover9000, 3);
+        let expected = vec![
+            "Starting stacks\n    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n",
+            "After move 3 of 4 (move 2 from 2 to 1)\n        [Z]\n        [N]\n[M]     [D]\n[C]     [P]\n 1   2   3 \n",
+            "After move 4 of 4 (move 1 from 1 to 2)\n        [Z]\n        [N]\n        [D]\n[C] [M] [P]\n 1   2   3 \n",
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn frames_stop_at_invalid_move() {
+        let (stacks, moves) =
+            parse_input("[Z] [M]\n 1   2 \n\nmove 2 from 2 to 1\nmove 1 from 1 to 2").unwrap();
+        let result = frames(&stacks, &moves, &CrateMover9000, 1);
+        let expected = vec![
+            "Starting stacks\n[Z] [M]\n 1   2 \n",
+            "Move 1 of 2 (move 2 from 2 to 1) cannot be made: stack 2 has 1 crates, 1 less than the move takes\n[Z] [M]\n 1   2 \n",
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn crate_mover_9000_onto_same_stack() {
+        let mut stacks = stacks_from(&["ZND", "MC"]);
+        let move_instruction = Move {
+            amount: 2,
+            source: 1,
+            destination: 1,
+        };
+        let expected = stacks_from(&["ZDN", "MC"]);
+
+        CrateMover9000
+            .apply(&mut stacks, &move_instruction)
+            .unwrap();
+
+        assert_eq!(stacks, expected);
+    }
+
+    #[test]
+    fn crate_mover_9001_with_large_move() {
+        let mut stacks = vec![vec!["A".to_string(); 1_000_000], vec![]];
+        stacks[0].push("B".to_string());
+        let move_instruction = Move {
+            amount: 1_000_000,
+            source: 1,
+            destination: 2,
+        };
+
+        CrateMover9001
+            .apply(&mut stacks, &move_instruction)
+            .unwrap();
+
+        assert_eq!(stacks[0], vec!["A".to_string()]);
+        assert_eq!(stacks[1].len(), 1_000_000);
+        assert_eq!(stacks[1].last().unwrap(), "B");
+    }
+
+    #[test]
+    fn limited_crate_mover_lifts() {
+        let stacks = stacks_from(&["ZN", "MCDEF", "P"]);
+        let crane = LimitedCrateMover {
+            capacity: NonZeroUsize::new(2).unwrap(),
+        };
+        let move_instruction = Move {
+            amount: 5,
+            source: 2,
+            destination: 3,
+        };
+        let result: Vec<usize> = crane
+            .lifts(&stacks, &move_instruction)
+            .unwrap()
+            .iter()
+            .map(|lift| lift.amount)
+            .collect();
+
+        assert_eq!(result, vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn yard_undo_restores_stacks() {
+        let (stacks, moves) = parse_input(Day05::EXAMPLE).unwrap();
+        let crane = LimitedCrateMover {
+            capacity: NonZeroUsize::new(2).unwrap(),
+        };
+        let mut yard = Yard::new(stacks.clone());
+
+        for instruction in &moves {
+            yard.apply(&crane, instruction).unwrap();
+        }
+
+        assert_eq!(yard.moves(), 4);
+        assert_eq!(yard.stacks(), stacks_from(&["M", "C", "PNDZ"]));
+
+        yard.rewind(2);
+
+        assert_eq!(yard.moves(), 2);
+        assert_eq!(yard.stacks(), stacks_from(&["", "MC", "PNDZ"]));
+
+        yard.rewind(10);
+
+        assert_eq!(yard.moves(), 0);
+        assert_eq!(yard.stacks(), stacks);
+        assert!(!yard.undo());
+    }
+
+    #[test]
+    fn yard_keeps_stacks_of_invalid_move() {
+        let mut yard = Yard::new(stacks_from(&["ZN", "MCD", "P"]));
+        let move_instruction = Move {
+            amount: 2,
+            source: 3,
+            destination: 1,
+        };
+        let result = yard.apply(&CrateMover9000, &move_instruction);
+
+        assert!(result.is_err());
+        assert_eq!(yard.moves(), 0);
+        assert_eq!(yard.stacks(), stacks_from(&["ZN", "MCD", "P"]));
+    }
+
+    #[test]
+    fn rearrange_stops_at_first_invalid_move() {
+        let (stacks, moves) = parse_input(
+            "[Z] [M]\n 1   2 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 2\nmove 0 from 0 to 1",
+        )
+        .unwrap();
+        let result = rearrange(&stacks, &moves, &CrateMover9000).unwrap_err();
+        let expected = InvalidMove {
+            instruction: 2,
+            error: MoveError::NotEnoughCrates {
+                stack: 1,
+                crates: 2,
+                shortfall: 1,
+            },
+        };
+
+        assert_eq!(result, expected);
+        assert_eq!(
+            Error::from(result).to_string(),
+            "cannot make move 2: stack 1 has 2 crates, 1 less than the move takes"
+        );
+    }
+
+    #[test]
+    fn dry_run_finds_every_invalid_move() {
+        let (stacks, moves) = parse_input(
+            "[Z] [M]\n 1   2 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 2\nmove 0 from 0 to 1\nmove 2 from 1 to 2",
+        )
+        .unwrap();
+        let result = dry_run(&stacks, &moves, &CrateMover9000);
+        let expected = vec![
+            InvalidMove {
+                instruction: 2,
+                error: MoveError::NotEnoughCrates {
+                    stack: 1,
+                    crates: 2,
+                    shortfall: 1,
+                },
+            },
+            InvalidMove {
+                instruction: 3,
+                error: MoveError::UnknownStack { stack: 0 },
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn get_top_crates_message_with_example_input() {
+        let stacks = stacks_from(&["ZN", "MCD", "P"]);
+        let expected = "NDP";
+        let result = get_top_crates_message(stacks);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,298 @@
+use std::{collections::VecDeque, io::BufRead};
+
+use crate::{input::bytes, Error, Part, Solution};
+
+#[derive(Debug, Default)]
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
+    const EXAMPLE: &'static str = include_str!("../../inputs/day06_example.txt");
+
+    type Input = String;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Self::PartOne, Error> {
+        find_marker_position(input, 4)?
+            .ok_or_else(|| Error::solve("start-of-packet marker not found"))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Self::PartTwo, Error> {
+        find_marker_position(input, 14)?
+            .ok_or_else(|| Error::solve("start-of-message marker not found"))
+    }
+
+    /// Reads the datastream one character at a time, and stops reading as soon
+    /// as the markers of the requested parts are found.
+    fn stream(
+        &self,
+        reader: &mut dyn BufRead,
+        part: Option<Part>,
+    ) -> Result<(Option<Self::PartOne>, Option<Self::PartTwo>), Error> {
+        let mut packet = (part != Some(Part::Two)).then(|| MarkerDetector::new(4));
+        let mut message = (part != Some(Part::One)).then(|| MarkerDetector::new(14));
+
+        let mut detectors: Vec<&mut MarkerDetector> =
+            [&mut packet, &mut message].into_iter().flatten().collect();
+        detect_markers(bytes(reader), &mut detectors)?;
+
+        let part_one = packet
+            .map(|detector| {
+                detector
+                    .position()
+                    .ok_or_else(|| Error::solve("start-of-packet marker not found"))
+            })
+            .transpose()?;
+        let part_two = message
+            .map(|detector| {
+                detector
+                    .position()
+                    .ok_or_else(|| Error::solve("start-of-message marker not found"))
+            })
+            .transpose()?;
+
+        Ok((part_one, part_two))
+    }
+}
+
+/// Detects a marker of `size` different characters in a datastream received
+/// one character at a time.
+#[derive(Debug)]
+struct MarkerDetector {
+    size: usize,
+    last_chars: VecDeque<char>,
+    received: usize,
+    position: Option<usize>,
+}
+
+impl MarkerDetector {
+    fn new(size: usize) -> MarkerDetector {
+        MarkerDetector {
+            size,
+            last_chars: VecDeque::with_capacity(size),
+            received: 0,
+            position: None,
+        }
+    }
+
+    /// Receives the next character of the datastream. Characters received
+    /// after the marker was found are ignored.
+    fn push(&mut self, c: char) {
+        if self.position.is_some() {
+            return;
+        }
+
+        if self.last_chars.len() == self.size {
+            self.last_chars.pop_front();
+        }
+        self.last_chars.push_back(c);
+        self.received += 1;
+
+        if self.last_chars.len() == self.size && !has_duplicates(self.last_chars.make_contiguous())
+        {
+            self.position = Some(self.received);
+        }
+    }
+
+    /// Number of characters received up to the end of the marker, if it was
+    /// found.
+    fn position(&self) -> Option<usize> {
+        self.position
+    }
+}
+
+/// Feeds the characters of the datastream to the `detectors` until all of them
+/// found their marker, without reading any further. Whitespace is only allowed
+/// at the start and at the end of the datastream, and any other character must
+/// be ASCII, so reading it all at once or as a stream gives the same result.
+fn detect_markers(
+    bytes: impl IntoIterator<Item = Result<u8, Error>>,
+    detectors: &mut [&mut MarkerDetector],
+) -> Result<(), Error> {
+    let mut started = false;
+    let mut whitespace = None;
+
+    for (i, byte) in bytes.into_iter().enumerate() {
+        let byte = byte?;
+
+        if byte.is_ascii_whitespace() {
+            if started {
+                whitespace.get_or_insert(i + 1);
+            }
+            continue;
+        }
+        if let Some(column) = whitespace {
+            return Err(Error::parse("unexpected whitespace in the datastream").at(1, column));
+        }
+        if !byte.is_ascii() {
+            return Err(Error::parse("the datastream can only hold ASCII characters").at(1, i + 1));
+        }
+
+        started = true;
+        for detector in detectors.iter_mut() {
+            detector.push(byte as char);
+        }
+
+        if detectors
+            .iter()
+            .all(|detector| detector.position().is_some())
+        {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Finds the position of the start-of-packet or start-of-message marker, based
+/// on the given `offset`. Returns `None` when the marker is not detected on the
+/// given `input`.
+fn find_marker_position(input: &str, offset: usize) -> Result<Option<usize>, Error> {
+    let mut detector = MarkerDetector::new(offset);
+    detect_markers(input.bytes().map(Ok), &mut [&mut detector])?;
+
+    Ok(detector.position())
+}
+
+fn has_duplicates(chars: &[char]) -> bool {
+    chars
+        .iter()
+        .enumerate()
+        .any(|(i, c)| chars[..i].contains(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Position;
+
+    #[test]
+    fn has_duplicates_with_example_input_parts() {
+        let inputs = vec![
+            (vec!['m', 'j', 'q', 'j'], true),
+            (vec!['j', 'q', 'j', 'p'], true),
+            (vec!['j', 'p', 'q', 'm'], false),
+        ];
+
+        for (input, expected) in inputs {
+            let result = has_duplicates(&input);
+
+            assert_eq!(
+                result, expected,
+                "wanted {}, got {} for {:?}",
+                expected, result, input
+            );
+        }
+    }
+
+    #[test]
+    fn find_marker_position_with_example_inputs() {
+        let inputs = vec![
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4, 7),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 4, 5),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 4, 6),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 4, 10),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 4, 11),
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14, 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 14, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 14, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 14, 26),
+        ];
+
+        for (input, offset, expected) in inputs {
+            let result = find_marker_position(input, offset).unwrap().unwrap();
+
+            assert_eq!(
+                result, expected,
+                "wanted {}, got {} for {}",
+                expected, result, input
+            );
+        }
+    }
+
+    #[test]
+    fn stream_with_example_input() {
+        let mut input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n".as_bytes();
+        let result = Day06.stream(&mut input, None).unwrap();
+
+        assert_eq!(result, (Some(7), Some(19)));
+    }
+
+    #[test]
+    fn stream_with_missing_start_of_message_marker() {
+        let input = "mjqjpqmgbljsph";
+
+        assert_eq!(
+            Day06
+                .stream(&mut input.as_bytes(), Some(Part::One))
+                .unwrap(),
+            (Some(7), None)
+        );
+        assert!(Day06.stream(&mut input.as_bytes(), None).is_err());
+    }
+
+    #[test]
+    fn stream_stops_reading_after_markers() {
+        let mut input: &[u8] = b"mjqjpqmgbljsphdztnvjfqwrcgsmlb\xff";
+        let result = Day06.stream(&mut input, None).unwrap();
+
+        assert_eq!(result, (Some(7), Some(19)));
+    }
+
+    #[test]
+    fn find_marker_position_with_empty_input() {
+        let input = "";
+        let result = find_marker_position(input, 4).unwrap();
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn find_marker_position_with_short_input() {
+        let input = "abc";
+        let result = find_marker_position(input, 4).unwrap();
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn stream_and_parse_agree_on_invalid_input() {
+        let inputs = [
+            "\n  mjqjpqmgbljsphdztnvjfqwrcgsmlb  \n",
+            "mjq jpqmgbljsphdztnvjfqwrcgsmlb",
+            "mjqjpqmgbljsph\ndztnvjfqwrcgsmlb",
+            "mjqjpqmgbljsphdztnvjfq\u{e9}wrcgsmlb",
+        ];
+
+        for input in inputs {
+            let stream = Day06.stream(&mut input.as_bytes(), None);
+            let parsed = Day06.parse(input).unwrap();
+            let solved = Day06
+                .part_one(&parsed)
+                .and_then(|part_one| Ok((Some(part_one), Some(Day06.part_two(&parsed)?))));
+
+            match (stream, solved) {
+                (Ok(stream), Ok(solved)) => assert_eq!(stream, solved, "for {:?}", input),
+                (Err(stream), Err(solved)) => {
+                    assert_eq!(stream.to_string(), solved.to_string(), "for {:?}", input)
+                }
+                (stream, solved) => panic!("{:?} and {:?} for {:?}", stream, solved, input),
+            }
+        }
+    }
+
+    #[test]
+    fn find_marker_position_with_whitespace_inside() {
+        let result = find_marker_position("mjq jpqmgbljsph", 4).unwrap_err();
+
+        assert_eq!(result.message(), "unexpected whitespace in the datastream");
+        assert_eq!(result.position(), Some(Position { line: 1, column: 4 }));
+    }
+}
@@ -0,0 +1,419 @@
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_till},
+    character::complete::{alpha1, newline},
+    sequence::separated_pair,
+    IResult,
+};
+use std::collections::BTreeMap;
+
+use crate::{
+    config::{parse_value, unknown_parameter},
+    parsing::{parse_all, separated_list1_committed},
+    Error, Solution,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+enum Operation {
+    Cd(Cd),
+    Ls(Vec<Files>),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Cd {
+    Root,
+    Up,
+    Down(String),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Files {
+    File { size: u32, name: String },
+    Dir(String),
+}
+
+/// Contents of each directory in the file system, keyed by their full path.
+pub type Tree = BTreeMap<String, Vec<Files>>;
+
+#[derive(Debug)]
+pub struct Day07 {
+    /// Largest size of the directories whose sizes are added up in part one.
+    pub max_size: u32,
+    /// Total space of the disk.
+    pub disk_size: u32,
+    /// Free space needed to run the update.
+    pub required_space: u32,
+}
+
+impl Default for Day07 {
+    fn default() -> Self {
+        Day07 {
+            max_size: 100_000,
+            disk_size: 70_000_000,
+            required_space: 30_000_000,
+        }
+    }
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "No Space Left On Device";
+    const EXAMPLE: &'static str = include_str!("../../inputs/day07_example.txt");
+
+    type Input = Tree;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        let operations = parse_all(input, parse_input)?;
+
+        Ok(build_tree(operations))
+    }
+
+    fn part_one(&self, tree: &Self::Input) -> Result<Self::PartOne, Error> {
+        get_total_sum_of_candidates_for_deletion(tree, self.max_size)
+    }
+
+    fn part_two(&self, tree: &Self::Input) -> Result<Self::PartTwo, Error> {
+        get_smallest_directory_size_to_be_deleted(tree, self.disk_size, self.required_space)
+    }
+
+    fn configure(&mut self, name: &str, value: Option<&str>) -> Result<(), Error> {
+        match name {
+            "max-size" => self.max_size = parse_value(name, value)?,
+            "disk-size" => self.disk_size = parse_value(name, value)?,
+            "required-space" => self.required_space = parse_value(name, value)?,
+            _ => {
+                return Err(unknown_parameter(
+                    name,
+                    &["max-size", "disk-size", "required-space"],
+                ))
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn get_total_sum_of_candidates_for_deletion(tree: &Tree, max_size: u32) -> Result<u32, Error> {
+    let mut sum = 0;
+
+    for (dir_name, _) in tree.iter() {
+        let dir_size = get_directory_size(tree, dir_name)?;
+        if dir_size <= max_size {
+            sum += dir_size;
+        }
+    }
+
+    Ok(sum)
+}
+
+/// Size of the smallest directory that frees enough space when deleted, or 0
+/// when there is already enough free space.
+fn get_smallest_directory_size_to_be_deleted(
+    tree: &Tree,
+    disk_size: u32,
+    required_space: u32,
+) -> Result<u32, Error> {
+    let used_space = get_directory_size(tree, "")?;
+    let free_space = disk_size
+        .checked_sub(used_space)
+        .ok_or_else(|| Error::solve("the files take more space than the disk has"))?;
+    let required_space = required_space.saturating_sub(free_space);
+    if required_space == 0 {
+        return Ok(0);
+    }
+    let mut min_size = used_space;
+
+    for (dir_name, _) in tree.iter() {
+        let dir_size = get_directory_size(tree, dir_name)?;
+        if dir_size >= required_space && dir_size < min_size {
+            min_size = dir_size;
+        }
+    }
+
+    Ok(min_size)
+}
+
+/// Total size of the files in a directory and its subdirectories, failing when
+/// one of them was never listed.
+fn get_directory_size(tree: &Tree, name: &str) -> Result<u32, Error> {
+    let dir_files = tree.get(name).ok_or_else(|| {
+        let path = if name.is_empty() { "/" } else { name };
+        Error::solve(format!("directory {path} is never listed"))
+    })?;
+    let mut sum = 0;
+
+    for f in dir_files {
+        match f {
+            Files::File { size, name: _ } => {
+                sum += size;
+            }
+            Files::Dir(value) => {
+                let child_name = [name, value].join("/");
+                sum += get_directory_size(tree, child_name.as_str())?;
+            }
+        }
+    }
+
+    Ok(sum)
+}
+
+fn build_tree(operations: Vec<Operation>) -> Tree {
+    let mut path_stack: Vec<String> = vec![];
+    let mut fs_tree = BTreeMap::new();
+
+    for op in operations {
+        match op {
+            Operation::Cd(Cd::Root) => {
+                path_stack.push(String::new());
+            }
+            Operation::Cd(Cd::Up) => {
+                path_stack.pop();
+            }
+            Operation::Cd(Cd::Down(name)) => {
+                path_stack.push(name);
+            }
+            Operation::Ls(files) => {
+                let path = path_stack.join("/");
+
+                fs_tree.entry(path).or_insert_with(Vec::new).extend(files);
+            }
+        }
+    }
+
+    fs_tree
+}
+
+/// I was getting some trouble with the parsing of this problem but thanks to
+/// the video of Chris Biscardi <https://www.youtube.com/watch?v=t9OQ3ca8OWk> I
+/// learned about `nom` and how to use it.
+fn parse_input(input: &str) -> IResult<&str, Vec<Operation>> {
+    let (input, cmd) = separated_list1_committed(newline, alt((ls, cd)))(input)?;
+
+    Ok((input, cmd))
+}
+
+fn cd(input: &str) -> IResult<&str, Operation> {
+    let (input, _) = tag("$ cd ")(input)?;
+    let (input, dir) = alt((tag("/"), tag(".."), alpha1))(input)?;
+
+    let op = match dir {
+        "/" => Operation::Cd(Cd::Root),
+        ".." => Operation::Cd(Cd::Up),
+        name => Operation::Cd(Cd::Down(name.to_string())),
+    };
+
+    Ok((input, op))
+}
+
+fn ls(input: &str) -> IResult<&str, Operation> {
+    let (input, _) = tag("$ ls")(input)?;
+    let (input, _) = newline(input)?;
+    let (input, files) = separated_list1_committed(newline, alt((directory, file)))(input)?;
+
+    Ok((input, Operation::Ls(files)))
+}
+
+fn directory(input: &str) -> IResult<&str, Files> {
+    let (input, _) = tag("dir ")(input)?;
+    let (input, name) = alpha1(input)?;
+
+    Ok((input, Files::Dir(name.to_string())))
+}
+
+fn file(input: &str) -> IResult<&str, Files> {
+    let (input, (size, name)) = separated_pair(
+        nom::character::complete::u32,
+        tag(" "),
+        take_till(|c| c == '\n'),
+    )(input)?;
+
+    Ok((
+        input,
+        Files::File {
+            size,
+            name: name.to_string(),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_cd_with_examples() {
+        let test_cases = &[
+            ("$ cd /", Operation::Cd(Cd::Root)),
+            ("$ cd ..", Operation::Cd(Cd::Up)),
+            ("$ cd foo", Operation::Cd(Cd::Down("foo".to_string()))),
+        ];
+
+        for t in test_cases {
+            let (_, result) = cd(t.0).unwrap();
+            assert_eq!(
+                result, t.1,
+                "got {:?}, wanted {:?} for {:?}",
+                result, t.1, t.0
+            );
+        }
+    }
+
+    #[test]
+    fn parse_ls_with_example() {
+        let input = "$ ls\ndir a\n14848514 b.txt\n";
+        let expected = Operation::Ls(vec![
+            Files::Dir("a".to_string()),
+            Files::File {
+                size: 14848514,
+                name: "b.txt".to_string(),
+            },
+        ]);
+        let (_, result) = ls(input).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_directory_with_example() {
+        let input = "dir a\n";
+        let expected = Files::Dir("a".to_string());
+        let (_, result) = directory(input).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_file_with_example() {
+        let input = "14848514 b.txt\n";
+        let expected = Files::File {
+            size: 14848514,
+            name: "b.txt".to_string(),
+        };
+        let (_, result) = file(input).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_input_with_example_input() {
+        let input = example_input();
+        let (input, result) = parse_input(input).unwrap();
+
+        assert!(input.is_empty());
+        assert_eq!(result.len(), 10);
+    }
+
+    #[test]
+    fn build_tree_with_example_input() {
+        let input = example_input();
+        let (_, operations) = parse_input(input).unwrap();
+        let tree = build_tree(operations);
+
+        assert!(!tree.is_empty());
+    }
+
+    #[test]
+    fn get_directory_size_with_example_input() {
+        let test_cases = &[
+            ("/a/e", 584),
+            ("/a", 94853),
+            ("/d", 24933642),
+            ("", 48381165),
+        ];
+        let input = example_input();
+        let (_, operations) = parse_input(input).unwrap();
+        let tree = build_tree(operations);
+
+        for t in test_cases {
+            let result = get_directory_size(&tree, t.0).unwrap();
+            assert_eq!(result, t.1, "wanted {}, got {} for {}", t.1, result, t.0);
+        }
+    }
+
+    #[test]
+    fn get_total_sum_of_candidates_for_deletion_with_example_input() {
+        let input = example_input();
+        let (_, operations) = parse_input(input).unwrap();
+        let tree = build_tree(operations);
+        let result = get_total_sum_of_candidates_for_deletion(&tree, 100_000);
+
+        assert_eq!(result.unwrap(), 95437);
+    }
+
+    #[test]
+    fn get_directory_size_with_unlisted_directory() {
+        let input = "$ cd /\n$ ls\ndir a\n14848514 b.txt";
+        let (_, operations) = parse_input(input).unwrap();
+        let tree = build_tree(operations);
+        let result = get_directory_size(&tree, "").unwrap_err();
+        let expected = "directory /a is never listed";
+
+        assert_eq!(result.message(), expected);
+    }
+
+    #[test]
+    fn get_smallest_directory_size_to_be_deleted_with_example_input() {
+        let input = example_input();
+        let (_, operations) = parse_input(input).unwrap();
+        let tree = build_tree(operations);
+        let result = get_smallest_directory_size_to_be_deleted(&tree, 70_000_000, 30_000_000);
+
+        assert_eq!(result.unwrap(), 24933642);
+    }
+
+    #[test]
+    fn get_smallest_directory_size_to_be_deleted_with_other_disk_sizes() {
+        let input = example_input();
+        let (_, operations) = parse_input(input).unwrap();
+        let tree = build_tree(operations);
+
+        let result = get_smallest_directory_size_to_be_deleted(&tree, 80_000_000, 30_000_000);
+        assert_eq!(result.unwrap(), 0);
+
+        let result = get_smallest_directory_size_to_be_deleted(&tree, 50_000_000, 30_000_000);
+        assert_eq!(result.unwrap(), 48381165);
+
+        let result = get_smallest_directory_size_to_be_deleted(&tree, 40_000_000, 30_000_000);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn configure_with_parameters() {
+        let mut day = Day07::default();
+        day.configure("max-size", Some("1000")).unwrap();
+        day.configure("disk-size", Some("50000000")).unwrap();
+
+        assert_eq!(day.max_size, 1000);
+        assert_eq!(day.disk_size, 50_000_000);
+        assert!(day.configure("max-size", Some("big")).is_err());
+        assert!(day.configure("min-size", Some("1")).is_err());
+    }
+
+    fn example_input() -> &'static str {
+        "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k" as _
+    }
+}
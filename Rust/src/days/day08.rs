@@ -1,11 +1,11 @@
-use std::{io::stdin, process::exit};
-
-use advent_of_code::read_input;
+use crate::{
+    parsing::{parse_all, separated_list1_committed},
+    Error, Solution,
+};
 use nom::{
-    character::complete::{anychar, newline},
-    combinator::verify,
-    multi::{many1, separated_list1},
-    IResult,
+    character::complete::{newline, satisfy},
+    multi::many1,
+    IResult, Parser,
 };
 
 #[derive(Debug)]
@@ -16,35 +16,49 @@ enum Direction {
     Bottom,
 }
 
-fn main() {
-    let input = read_input(&mut stdin()).unwrap_or_else(|err| {
-        eprintln!("Could not read input: {:?}", err);
-        exit(1);
-    });
-    let (_, matrix) = parse_input(&input).unwrap_or_else(|err| {
-        eprintln!("Coult not parse input: {:?}", err);
-        exit(2);
-    });
-
-    println!(
-        "Total of trees visible outside the grid: {}",
-        total_visible(&matrix)
-    );
-
-    println!("Max scenic score: {}", max_scenic_score(&matrix));
+#[derive(Debug, Default)]
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Treetop Tree House";
+    const EXAMPLE: &'static str = include_str!("../../inputs/day08_example.txt");
+
+    type Input = Vec<Vec<u32>>;
+    type PartOne = u32;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        let matrix = parse_all(input, parse_input)?;
+        let width = matrix[0].len();
+
+        for (index, row) in matrix.iter().enumerate() {
+            if row.len() != width {
+                let message = format!("row has {} trees, expected {}", row.len(), width);
+                return Err(Error::parse(message).at(index + 1, row.len().min(width) + 1));
+            }
+        }
+
+        Ok(matrix)
+    }
+
+    fn part_one(&self, matrix: &Self::Input) -> Result<Self::PartOne, Error> {
+        Ok(total_visible(matrix))
+    }
+
+    fn part_two(&self, matrix: &Self::Input) -> Result<Self::PartTwo, Error> {
+        Ok(max_scenic_score(matrix))
+    }
 }
 
 fn parse_input(input: &str) -> IResult<&str, Vec<Vec<u32>>> {
-    let (input, matrix) = separated_list1(newline, parse_line)(input)?;
+    let (input, matrix) = separated_list1_committed(newline, parse_line)(input)?;
 
     Ok((input, matrix))
 }
 
 fn parse_line(input: &str) -> IResult<&str, Vec<u32>> {
-    let (input, x) = many1(verify(anychar, |c| *c != '\n'))(input)?;
-    let digits = x.iter().map(|c| c.to_digit(10).unwrap()).collect();
-
-    Ok((input, digits))
+    many1(satisfy(|c| c.is_ascii_digit()).map(|c| c.to_digit(10).unwrap()))(input)
 }
 
 fn total_visible(matrix: &[Vec<u32>]) -> u32 {
@@ -100,7 +114,7 @@ fn is_visible(matrix: &[Vec<u32>], (x, y): (usize, usize)) -> bool {
     directions_hidden.len() < 4
 }
 
-fn max_scenic_score(matrix: &Vec<Vec<u32>>) -> usize {
+fn max_scenic_score(matrix: &[Vec<u32>]) -> usize {
     let mut max = 0;
 
     for (x, row) in matrix.iter().enumerate() {
@@ -115,7 +129,7 @@ fn max_scenic_score(matrix: &Vec<Vec<u32>>) -> usize {
     max
 }
 
-fn scenic_score(matrix: &Vec<Vec<u32>>, (x, y): (usize, usize)) -> usize {
+fn scenic_score(matrix: &[Vec<u32>], (x, y): (usize, usize)) -> usize {
     let height = matrix[x][y];
     let mut score = 1;
 
@@ -161,6 +175,7 @@ fn scenic_score(matrix: &Vec<Vec<u32>>, (x, y): (usize, usize)) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Position;
 
     #[test]
     fn parse_line_with_example_input() {
@@ -191,6 +206,19 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn parse_with_ragged_rows() {
+        let result = Day08.parse("123\n45\n678\n").unwrap_err();
+
+        assert_eq!(result.message(), "row has 2 trees, expected 3");
+        assert_eq!(result.position(), Some(Position { line: 2, column: 3 }));
+
+        let result = Day08.parse("123\n4567\n").unwrap_err();
+
+        assert_eq!(result.message(), "row has 4 trees, expected 3");
+        assert_eq!(result.position(), Some(Position { line: 2, column: 4 }));
+    }
+
     #[test]
     fn is_visible_with_example_input() {
         let test_cases = vec![
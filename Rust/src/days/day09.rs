@@ -1,9 +1,9 @@
-use std::{collections::HashSet, io::stdin, process::exit, str::FromStr};
+use std::{collections::HashSet, io::BufRead, str::FromStr};
 
-use advent_of_code::read_input;
+use crate::{input::lines, Error, Part, Solution};
 
 #[derive(Debug, PartialEq, Eq)]
-enum Motion {
+pub enum Motion {
     Right(usize),
     Left(usize),
     Up(usize),
@@ -83,6 +83,15 @@ struct Rope {
     visited: HashSet<Point>,
 }
 
+impl Rope {
+    fn new(length: usize) -> Rope {
+        Rope {
+            knots: vec![Point::default(); length],
+            ..Default::default()
+        }
+    }
+}
+
 impl FromStr for Motion {
     type Err = String;
 
@@ -111,46 +120,67 @@ impl FromStr for Motion {
     }
 }
 
-fn main() {
-    let input = read_input(&mut stdin()).unwrap_or_else(|err| {
-        eprintln!("Could not read input: {:?}", err);
-        exit(1);
-    });
-    let motions = parse_input(&input).unwrap_or_else(|err| {
-        eprintln!("Coult not parse input: {:?}", err);
-        exit(2);
-    });
-
-    println!(
-        "Tail positions with rope of length 2: {}",
-        unique_tail_positions(&motions, 2).len()
-    );
-
-    println!(
-        "Tail positions with rope of length 10: {}",
-        unique_tail_positions(&motions, 10).len()
-    );
+#[derive(Debug, Default)]
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Rope Bridge";
+    const EXAMPLE: &'static str = include_str!("../../inputs/day09_example.txt");
+
+    type Input = Vec<Motion>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, motions: &Self::Input) -> Result<Self::PartOne, Error> {
+        Ok(unique_tail_positions(motions, 2).len())
+    }
+
+    fn part_two(&self, motions: &Self::Input) -> Result<Self::PartTwo, Error> {
+        Ok(unique_tail_positions(motions, 10).len())
+    }
+
+    /// Moves the ropes of both parts as each motion is read, so only the
+    /// visited positions are kept in memory.
+    fn stream(
+        &self,
+        reader: &mut dyn BufRead,
+        part: Option<Part>,
+    ) -> Result<(Option<Self::PartOne>, Option<Self::PartTwo>), Error> {
+        let mut short_rope = (part != Some(Part::Two)).then(|| Rope::new(2));
+        let mut long_rope = (part != Some(Part::One)).then(|| Rope::new(10));
+
+        for line in lines(reader) {
+            let (line_number, line) = line?;
+            let motion = parse_motion(&line, line_number)?;
+
+            for rope in [&mut short_rope, &mut long_rope].into_iter().flatten() {
+                apply_motion(rope, &motion);
+            }
+        }
+
+        Ok((
+            short_rope.map(|rope| rope.visited.len()),
+            long_rope.map(|rope| rope.visited.len()),
+        ))
+    }
 }
 
-fn unique_tail_positions(motions: &Vec<Motion>, rope_length: usize) -> HashSet<Point> {
-    let mut positions = HashSet::new();
-    let knots = (0..rope_length).map(|_| Point::default()).collect();
-    let mut rope = Rope {
-        knots,
-        ..Default::default()
-    };
+fn unique_tail_positions(motions: &[Motion], rope_length: usize) -> HashSet<Point> {
+    let mut rope = Rope::new(rope_length);
 
-    positions.insert(Point(0, 0));
     for motion in motions {
-        rope = apply_motion(&rope, motion);
+        apply_motion(&mut rope, motion);
     }
 
     rope.visited
 }
 
-fn apply_motion(rope: &Rope, motion: &Motion) -> Rope {
-    let mut rope = rope.clone();
-
+fn apply_motion(rope: &mut Rope, motion: &Motion) {
     let steps = match motion {
         Motion::Right(steps) => *steps,
         Motion::Left(steps) => *steps,
@@ -191,12 +221,19 @@ fn apply_motion(rope: &Rope, motion: &Motion) -> Rope {
         rope.visited.insert(knots.last().cloned().unwrap());
         rope.knots = knots;
     }
+}
 
-    rope
+fn parse_input(input: &str) -> Result<Vec<Motion>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| parse_motion(l, i + 1))
+        .collect()
 }
 
-fn parse_input(input: &str) -> Result<Vec<Motion>, String> {
-    input.lines().map(|l| l.parse()).collect()
+fn parse_motion(line: &str, line_number: usize) -> Result<Motion, Error> {
+    line.parse()
+        .map_err(|err: String| Error::parse(err).at(line_number, 1))
 }
 
 #[cfg(test)]
@@ -307,7 +344,7 @@ R 2";
         };
 
         for motion in motions {
-            rope = apply_motion(&rope, &motion);
+            apply_motion(&mut rope, &motion);
         }
 
         assert_eq!(rope, expected);
@@ -326,7 +363,7 @@ R 2";
         };
 
         for motion in motions {
-            rope = apply_motion(&rope, &motion);
+            apply_motion(&mut rope, &motion);
         }
 
         assert_eq!(rope, expected);
@@ -382,4 +419,19 @@ R 2";
 
         assert_eq!(positions.len(), 36);
     }
+
+    #[test]
+    fn stream_with_example_input() {
+        let result = Day09.stream(&mut EXAMPLE_INPUT.as_bytes(), None).unwrap();
+
+        assert_eq!(result, (Some(13), Some(1)));
+    }
+
+    #[test]
+    fn stream_with_invalid_motion() {
+        let mut input = "R 4\nU 4\nX 3\n".as_bytes();
+        let result = Day09.stream(&mut input, Some(Part::Two)).unwrap_err();
+
+        assert_eq!(result.to_string(), "line 3, column 1: invalid direction");
+    }
 }
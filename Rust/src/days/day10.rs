@@ -0,0 +1,359 @@
+use std::io::BufRead;
+
+use crate::{
+    config::{parse_value, unknown_parameter},
+    input::lines,
+    parsing::parse_all,
+    Error, Part, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Add(i32),
+}
+
+/// Signal strengths are added up on these cycles.
+const INTERESTING_CYCLES: [i32; 6] = [20, 60, 100, 140, 180, 220];
+/// Signal strengths are only checked before this cycle.
+const LAST_CYCLE: i32 = 240;
+
+#[derive(Debug)]
+struct Processor<I: Iterator<Item = Instruction>> {
+    instructions: I,
+    current_instruction: Option<Instruction>,
+    cycle: i32,
+    instruction_cycle: u32,
+    register: i32,
+}
+
+impl<I: Iterator<Item = Instruction>> Processor<I> {
+    /// Creates a processor that fetches each instruction from `instructions`
+    /// only when it is about to run it.
+    pub fn new(mut instructions: I) -> Processor<I> {
+        Processor {
+            current_instruction: instructions.next(),
+            instructions,
+            cycle: 1,
+            instruction_cycle: 0,
+            register: 1,
+        }
+    }
+
+    /// Runs a single cycle. Once every instruction was run the processor keeps
+    /// counting cycles without changing the register.
+    pub fn next_cycle(&mut self) {
+        match self.current_instruction {
+            None => {}
+            Some(Instruction::Noop) => self.next_instruction(),
+            Some(Instruction::Add(value)) => {
+                if self.instruction_cycle == 1 {
+                    self.register += value;
+                    self.next_instruction();
+                } else {
+                    self.instruction_cycle += 1;
+                }
+            }
+        }
+
+        self.cycle += 1;
+    }
+
+    fn next_instruction(&mut self) {
+        self.current_instruction = self.instructions.next();
+        self.instruction_cycle = 0;
+    }
+}
+
+/// CRT screen, drawn one pixel per cycle.
+#[derive(Debug)]
+struct Screen {
+    width: usize,
+    height: usize,
+    pixels: String,
+    drawn: usize,
+}
+
+impl Screen {
+    fn new(width: usize, height: usize) -> Screen {
+        Screen {
+            width,
+            height,
+            pixels: String::new(),
+            drawn: 0,
+        }
+    }
+
+    /// Draws the next pixel, lit if the sprite centered at `register` covers
+    /// it. Nothing is drawn once the screen is complete.
+    fn draw(&mut self, register: i32) {
+        if self.is_complete() {
+            return;
+        }
+
+        let column = self.drawn % self.width;
+        let sprite_location = register - 1..=register + 1;
+
+        if sprite_location.contains(&(column as i32)) {
+            self.pixels.push('#');
+        } else {
+            self.pixels.push(' ');
+        }
+        if column == self.width - 1 {
+            self.pixels.push('\n');
+        }
+        self.drawn += 1;
+    }
+
+    fn is_complete(&self) -> bool {
+        self.drawn == self.width * self.height
+    }
+}
+
+#[derive(Debug)]
+pub struct Day10 {
+    /// Number of pixels of each row of the screen.
+    pub screen_width: usize,
+    /// Number of rows of the screen.
+    pub screen_height: usize,
+}
+
+impl Default for Day10 {
+    fn default() -> Self {
+        Day10 {
+            screen_width: 40,
+            screen_height: 6,
+        }
+    }
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Cathode-Ray Tube";
+    const EXAMPLE: &'static str = include_str!("../../inputs/day10_example.txt");
+
+    type Input = Vec<Instruction>;
+    type PartOne = i32;
+    type PartTwo = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        let instructions = parse_all(input, parser::parse_input)?;
+
+        Ok(instructions)
+    }
+
+    fn part_one(&self, instructions: &Self::Input) -> Result<Self::PartOne, Error> {
+        Ok(signal_strengths(instructions))
+    }
+
+    fn part_two(&self, instructions: &Self::Input) -> Result<Self::PartTwo, Error> {
+        Ok(draw_screen(
+            instructions,
+            self.screen_width,
+            self.screen_height,
+        ))
+    }
+
+    /// Runs each instruction as it is read, and stops reading once every
+    /// cycle needed by the requested parts was run, so instructions after
+    /// those cycles are not checked.
+    fn stream(
+        &self,
+        reader: &mut dyn BufRead,
+        part: Option<Part>,
+    ) -> Result<(Option<Self::PartOne>, Option<Self::PartTwo>), Error> {
+        let mut error = None;
+        let instructions = lines(reader)
+            .filter(|line| !matches!(line, Ok((_, line)) if line.trim().is_empty()))
+            .map_while(|line| {
+                let instruction = line.and_then(|(line_number, line)| {
+                    parse_all(line.as_str(), parser::parse_instruction)
+                        .map_err(|err| err.on_line(line_number))
+                });
+
+                instruction.map_err(|err| error = Some(err)).ok()
+            });
+        let mut processor = Processor::new(instructions);
+        let mut signal_strength = (part != Some(Part::Two)).then_some(0);
+        let mut screen =
+            (part != Some(Part::One)).then(|| Screen::new(self.screen_width, self.screen_height));
+
+        loop {
+            let signal_strength_done = signal_strength.is_none() || processor.cycle >= LAST_CYCLE;
+            let screen_done = screen.as_ref().is_none_or(Screen::is_complete);
+            if signal_strength_done && screen_done {
+                break;
+            }
+
+            if let Some(total) = &mut signal_strength {
+                *total += signal_strength_at(processor.cycle, processor.register);
+            }
+            if let Some(screen) = &mut screen {
+                screen.draw(processor.register);
+            }
+            processor.next_cycle();
+        }
+
+        drop(processor);
+        if let Some(err) = error {
+            return Err(err);
+        }
+
+        Ok((signal_strength, screen.map(|screen| screen.pixels)))
+    }
+
+    fn configure(&mut self, name: &str, value: Option<&str>) -> Result<(), Error> {
+        match name {
+            "width" => self.screen_width = parse_value(name, value)?,
+            "height" => self.screen_height = parse_value(name, value)?,
+            _ => return Err(unknown_parameter(name, &["width", "height"])),
+        }
+
+        Ok(())
+    }
+}
+
+fn draw_screen(instructions: &[Instruction], width: usize, height: usize) -> String {
+    let mut processor = Processor::new(instructions.iter().copied());
+    let mut screen = Screen::new(width, height);
+
+    while !screen.is_complete() {
+        screen.draw(processor.register);
+        processor.next_cycle();
+    }
+
+    screen.pixels
+}
+
+fn signal_strengths(instructions: &[Instruction]) -> i32 {
+    let mut processor = Processor::new(instructions.iter().copied());
+    let mut result = 0;
+
+    while processor.cycle < LAST_CYCLE {
+        result += signal_strength_at(processor.cycle, processor.register);
+        processor.next_cycle();
+    }
+
+    result
+}
+
+/// Signal strength during the given `cycle`, if it is one of the interesting
+/// cycles.
+fn signal_strength_at(cycle: i32, register: i32) -> i32 {
+    if INTERESTING_CYCLES.contains(&cycle) {
+        cycle * register
+    } else {
+        0
+    }
+}
+
+mod parser {
+    //! Parses input from Advent of Code 2022 (Day 10) problem.
+    //!
+    //! The input is very simple. It is a list of instructions and there are only two possible
+    //! instructions:
+    //! - `noop`
+    //! - `addx V` where `V` is an signed integer
+
+    use super::Instruction;
+    use crate::parsing::separated_list1_committed;
+    use nom::{branch::alt, bytes::complete::tag, character::complete::newline, IResult};
+
+    pub fn parse_input(input: &str) -> IResult<&str, Vec<Instruction>> {
+        let (input, instructions) = separated_list1_committed(newline, parse_instruction)(input)?;
+
+        Ok((input, instructions))
+    }
+
+    pub fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+        alt((noop, addx))(input)
+    }
+
+    fn noop(input: &str) -> IResult<&str, Instruction> {
+        let (input, _) = tag("noop")(input)?;
+
+        Ok((input, Instruction::Noop))
+    }
+
+    fn addx(input: &str) -> IResult<&str, Instruction> {
+        let (input, _) = tag("addx ")(input)?;
+        let (input, value) = nom::character::complete::i32(input)?;
+        let instruction = Instruction::Add(value);
+
+        Ok((input, instruction))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::Position;
+
+    #[test]
+    fn parse_input_with_small_example_input() {
+        let input = "noop
+addx 3
+addx -5";
+        let (input, result) = parser::parse_input(input).unwrap();
+        let expected = vec![Instruction::Noop, Instruction::Add(3), Instruction::Add(-5)];
+
+        assert_eq!(result, expected);
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn signal_strengths_with_large_example_input() {
+        let input = fs::read_to_string("inputs/day10_example.txt").unwrap();
+        let (_, instructions) = parser::parse_input(input.as_str()).unwrap();
+        let result = signal_strengths(&instructions);
+
+        assert_eq!(result, 13140);
+    }
+
+    #[test]
+    fn draw_screen_with_smaller_screen() {
+        let instructions = vec![Instruction::Noop, Instruction::Add(3), Instruction::Add(-5)];
+        let result = draw_screen(&instructions, 4, 2);
+
+        assert_eq!(result, "####\n    \n");
+    }
+
+    #[test]
+    fn stream_with_large_example_input() {
+        let input = fs::read_to_string("inputs/day10_example.txt").unwrap();
+        let (_, instructions) = parser::parse_input(input.as_str()).unwrap();
+        let result = Day10::default()
+            .stream(&mut input.as_bytes(), None)
+            .unwrap();
+
+        assert_eq!(result.0, Some(13140));
+        assert_eq!(result.1, Some(draw_screen(&instructions, 40, 6)));
+    }
+
+    #[test]
+    fn stream_with_invalid_instruction() {
+        let mut input = "noop\naddx 3\nadx -5\n".as_bytes();
+        let result = Day10::default().stream(&mut input, None).unwrap_err();
+
+        assert_eq!(
+            result.to_string(),
+            "line 3, column 1: invalid input (tag)\n3 | adx -5\n  | ^"
+        );
+    }
+
+    #[test]
+    fn parse_and_stream_report_same_position() {
+        let input = "noop\naddx 3\naddx -x\n";
+        let day = Day10::default();
+        let parse_error = day.parse(input).unwrap_err();
+        let stream_error = day.stream(&mut input.as_bytes(), None).unwrap_err();
+
+        assert_eq!(
+            parse_error.position(),
+            Some(Position { line: 3, column: 6 })
+        );
+        assert_eq!(stream_error.position(), parse_error.position());
+    }
+}
@@ -1,6 +1,10 @@
-use std::{io::stdin, process::exit};
+use std::num::NonZeroU64;
 
-use advent_of_code::read_input;
+use crate::{
+    config::{parse_value, unknown_parameter},
+    parsing::parse_all,
+    Error, Solution,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Monkey {
@@ -20,7 +24,7 @@ impl Monkey {
     fn execute_test(&self, value: u64) -> u64 {
         match self.test {
             Test::Divisible(divisor, then_val, else_val) => {
-                if value % divisor == 0 {
+                if value.is_multiple_of(divisor) {
                     then_val
                 } else {
                     else_val
@@ -56,27 +60,116 @@ enum Test {
     Divisible(u64, u64, u64),
 }
 
-fn main() {
-    let input = read_input(&mut stdin()).unwrap_or_else(|err| {
-        eprintln!("Could not read input: {:?}", err);
-        exit(1);
-    });
-    let (_, monkeys) = parser::parse_input(&input).unwrap_or_else(|err| {
-        eprintln!("Coult not parse input: {:?}", err);
-        exit(2);
-    });
+#[derive(Debug)]
+pub struct Day11 {
+    /// Number of rounds of part one.
+    pub part_one_rounds: usize,
+    /// Worry levels are divided by this factor after each inspection in part
+    /// one.
+    pub part_one_relief: NonZeroU64,
+    /// Number of rounds of part two.
+    pub part_two_rounds: usize,
+    /// Worry levels are divided by this factor after each inspection in part
+    /// two.
+    pub part_two_relief: NonZeroU64,
+}
 
-    let mut part_1_monkeys = monkeys.clone();
-    println!(
-        "Monkey business level with worry level relief of 3: {}",
-        get_monkey_business_level(&mut part_1_monkeys, 20, 3)
-    );
-
-    let mut part_2_monkeys = monkeys;
-    println!(
-        "Monkey business level with worry level relief of 1: {}",
-        get_monkey_business_level(&mut part_2_monkeys, 10000, 1)
-    );
+impl Default for Day11 {
+    fn default() -> Self {
+        Day11 {
+            part_one_rounds: 20,
+            part_one_relief: NonZeroU64::new(3).unwrap(),
+            part_two_rounds: 10000,
+            part_two_relief: NonZeroU64::MIN,
+        }
+    }
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Monkey in the Middle";
+    const EXAMPLE: &'static str = include_str!("../../inputs/day11_example.txt");
+
+    type Input = Vec<Monkey>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        let monkeys = parse_all(input, parser::parse_input)?;
+        check_tests(input, monkeys.len())?;
+
+        Ok(monkeys)
+    }
+
+    fn part_one(&self, monkeys: &Self::Input) -> Result<Self::PartOne, Error> {
+        Ok(get_monkey_business_level(
+            &mut monkeys.clone(),
+            self.part_one_rounds,
+            self.part_one_relief.get(),
+        ))
+    }
+
+    fn part_two(&self, monkeys: &Self::Input) -> Result<Self::PartTwo, Error> {
+        Ok(get_monkey_business_level(
+            &mut monkeys.clone(),
+            self.part_two_rounds,
+            self.part_two_relief.get(),
+        ))
+    }
+
+    fn configure(&mut self, name: &str, value: Option<&str>) -> Result<(), Error> {
+        match name {
+            "part-one-rounds" => self.part_one_rounds = parse_value(name, value)?,
+            "part-one-relief" => self.part_one_relief = parse_value(name, value)?,
+            "part-two-rounds" => self.part_two_rounds = parse_value(name, value)?,
+            "part-two-relief" => self.part_two_relief = parse_value(name, value)?,
+            _ => {
+                return Err(unknown_parameter(
+                    name,
+                    &[
+                        "part-one-rounds",
+                        "part-one-relief",
+                        "part-two-rounds",
+                        "part-two-relief",
+                    ],
+                ))
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that the tests of the monkeys divide by a number other than zero
+/// and throw to monkeys that exist, pointing to the offending number.
+fn check_tests(input: &str, monkeys: usize) -> Result<(), Error> {
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        let value = |prefix: &str| {
+            line.strip_prefix(prefix)
+                .map(|rest| (rest.trim_end().parse::<u64>(), offset + prefix.len()))
+        };
+
+        if let Some((Ok(0), at)) = value("  Test: divisible by ") {
+            return Err(Error::parse("cannot test divisibility by 0").at_offset(input, at));
+        }
+        for prefix in [
+            "    If true: throw to monkey ",
+            "    If false: throw to monkey ",
+        ] {
+            if let Some((Ok(target), at)) = value(prefix) {
+                if target >= monkeys as u64 {
+                    let message = format!("there is no monkey {target} to throw to");
+                    return Err(Error::parse(message).at_offset(input, at));
+                }
+            }
+        }
+
+        offset += line.len();
+    }
+
+    Ok(())
 }
 
 fn get_monkey_business_level(
@@ -127,18 +220,15 @@ fn process_round(
 }
 
 mod parser {
-    use crate::{Monkey, Operand, Operation, Test};
+    use super::{Monkey, Operand, Operation, Test};
+    use crate::parsing::separated_list1_committed;
     use nom::{
-        branch::alt,
-        bytes::complete::tag,
-        character::complete::newline,
-        multi::{separated_list0, separated_list1},
-        sequence::separated_pair,
-        IResult,
+        branch::alt, bytes::complete::tag, character::complete::newline, multi::separated_list0,
+        sequence::separated_pair, IResult,
     };
 
     pub fn parse_input(input: &str) -> IResult<&str, Vec<Monkey>> {
-        separated_list1(tag("\n\n"), monkey)(input)
+        separated_list1_committed(tag("\n\n"), monkey)(input)
     }
 
     fn monkey(input: &str) -> IResult<&str, Monkey> {
@@ -232,6 +322,7 @@ mod parser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Position;
     use std::fs;
 
     #[test]
@@ -282,4 +373,45 @@ mod tests {
 
         assert_eq!(result, 2713310158);
     }
+
+    #[test]
+    fn configure_with_parameters() {
+        let mut day = Day11::default();
+        day.configure("part-two-rounds", Some("20")).unwrap();
+
+        assert_eq!(day.part_two_rounds, 20);
+        assert!(day.configure("part-one-relief", Some("0")).is_err());
+        assert!(day.configure("rounds", Some("20")).is_err());
+    }
+
+    #[test]
+    fn parse_with_invalid_tests() {
+        let monkey = |divisor: u64, target: u64| {
+            format!(
+                "Monkey 0:\n  Starting items: 79\n  Operation: new = old * 19\n  \
+                 Test: divisible by {divisor}\n    If true: throw to monkey 0\n    \
+                 If false: throw to monkey {target}\n"
+            )
+        };
+
+        let result = Day11::default().parse(&monkey(0, 0)).unwrap_err();
+        assert_eq!(result.message(), "cannot test divisibility by 0");
+        assert_eq!(
+            result.position(),
+            Some(Position {
+                line: 4,
+                column: 22
+            })
+        );
+
+        let result = Day11::default().parse(&monkey(23, 5)).unwrap_err();
+        assert_eq!(result.message(), "there is no monkey 5 to throw to");
+        assert_eq!(
+            result.position(),
+            Some(Position {
+                line: 6,
+                column: 31
+            })
+        );
+    }
 }
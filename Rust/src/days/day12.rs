@@ -1,13 +1,11 @@
-use std::{
-    collections::{BinaryHeap, HashMap, HashSet},
-    io::stdin,
-    process::exit,
-};
+use std::collections::{BinaryHeap, HashMap, HashSet};
 
-use advent_of_code::read_input;
+use crate::{
+    parsing::{parse_all, separated_list1_committed},
+    Error, Solution,
+};
 use nom::{
     character::complete::{alpha1, newline},
-    multi::separated_list1,
     IResult, Parser,
 };
 
@@ -15,7 +13,7 @@ use nom::{
 struct Point(usize, usize);
 
 #[derive(Debug, PartialEq, Eq)]
-struct Heightmap {
+pub struct Heightmap {
     values: Vec<Vec<usize>>,
     start: Point,
     end: Point,
@@ -46,23 +44,35 @@ impl PartialOrd for Position {
 }
 
 impl Heightmap {
-    fn new(heightmap: &[Vec<char>]) -> Heightmap {
-        let start = Heightmap::find_position(heightmap, 'S').unwrap();
-        let end = Heightmap::find_position(heightmap, 'E').unwrap();
-        let values: Vec<Vec<usize>> = heightmap
+    /// Builds the heightmap from its rows of letters, failing when the start
+    /// or the end is missing or when a letter is not a height.
+    fn new(heightmap: &[Vec<char>]) -> Result<Heightmap, Error> {
+        let marker = |needle: char, name: &str| {
+            Heightmap::find_position(heightmap, needle).ok_or_else(|| {
+                let line = heightmap.len();
+                let column = heightmap.last().map_or(0, Vec::len) + 1;
+                Error::parse(format!("missing the {name} position `{needle}`")).at(line, column)
+            })
+        };
+        let start = marker('S', "start")?;
+        let end = marker('E', "end")?;
+        let values = heightmap
             .iter()
-            .map(|row| {
+            .enumerate()
+            .map(|(y, row)| {
                 row.iter()
-                    .map(|&val| match val {
-                        'S' => 0,
-                        'E' => 25,
-                        _ => val as usize - 'a' as usize,
+                    .enumerate()
+                    .map(|(x, &val)| match val {
+                        'S' => Ok(0),
+                        'E' => Ok(25),
+                        'a'..='z' => Ok(val as usize - 'a' as usize),
+                        _ => Err(Error::parse(format!("invalid height `{val}`")).at(y + 1, x + 1)),
                     })
                     .collect()
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        Heightmap { values, start, end }
+        Ok(Heightmap { values, start, end })
     }
 
     fn find_position(heightmap: &[Vec<char>], needle: char) -> Option<Point> {
@@ -117,25 +127,34 @@ impl Heightmap {
     }
 }
 
-fn main() {
-    let input = read_input(&mut stdin()).unwrap_or_else(|err| {
-        eprintln!("Could not read input: {:?}", err);
-        exit(1);
-    });
-    let (_, heightmap) = parse_input(&input).unwrap_or_else(|err| {
-        eprintln!("Coult not parse input: {:?}", err);
-        exit(2);
-    });
+#[derive(Debug, Default)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hill Climbing Algorithm";
+    const EXAMPLE: &'static str = include_str!("../../inputs/day12_example.txt");
 
-    println!(
-        "Shortest path length from start: {}",
-        shortest_path(&heightmap, heightmap.start).unwrap().len()
-    );
+    type Input = Heightmap;
+    type PartOne = usize;
+    type PartTwo = usize;
 
-    println!(
-        "Shortest path length: {}",
-        min_shortest_path(&heightmap).len()
-    );
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        let heightmap = parse_all(input, parse_input)?;
+
+        Heightmap::new(&heightmap)
+    }
+
+    fn part_one(&self, heightmap: &Self::Input) -> Result<Self::PartOne, Error> {
+        let path = shortest_path(heightmap, heightmap.start)
+            .ok_or_else(|| Error::solve("no path found from the starting position"))?;
+
+        Ok(path.len())
+    }
+
+    fn part_two(&self, heightmap: &Self::Input) -> Result<Self::PartTwo, Error> {
+        Ok(min_shortest_path(heightmap).len())
+    }
 }
 
 fn min_shortest_path(heightmap: &Heightmap) -> Vec<Point> {
@@ -237,10 +256,9 @@ fn manhattan_distance(Point(x1, y1): Point, Point(x2, y2): Point) -> usize {
     x1.abs_diff(x2) + y1.abs_diff(y2)
 }
 
-fn parse_input(input: &str) -> IResult<&str, Heightmap> {
+fn parse_input(input: &str) -> IResult<&str, Vec<Vec<char>>> {
     let (input, heightmap) =
-        separated_list1(newline, alpha1.map(|l: &str| l.chars().collect()))(input)?;
-    let heightmap = Heightmap::new(&heightmap);
+        separated_list1_committed(newline, alpha1.map(|l: &str| l.chars().collect()))(input)?;
 
     Ok((input, heightmap))
 }
@@ -257,7 +275,8 @@ abdefghi";
 
     #[test]
     fn parse_input_with_example_input() {
-        let (input, result) = parse_input(EXAMPLE_INPUT).unwrap();
+        let (input, heightmap) = parse_input(EXAMPLE_INPUT).unwrap();
+        let result = Heightmap::new(&heightmap).unwrap();
         let values = vec![
             vec![0, 0, 1, 16, 15, 14, 13, 12],
             vec![0, 1, 2, 17, 24, 23, 23, 11],
@@ -275,6 +294,32 @@ abdefghi";
         assert!(input.is_empty());
     }
 
+    #[test]
+    fn parse_with_missing_markers() {
+        let result = Day12.parse("Sab\nabc").unwrap_err();
+
+        assert_eq!(result.message(), "missing the end position `E`");
+        assert_eq!(
+            result.position(),
+            Some(crate::Position { line: 2, column: 4 })
+        );
+
+        let result = Day12.parse("aab\nabE").unwrap_err();
+
+        assert_eq!(result.message(), "missing the start position `S`");
+    }
+
+    #[test]
+    fn parse_with_invalid_height() {
+        let result = Day12.parse("SaB\nabE").unwrap_err();
+
+        assert_eq!(result.message(), "invalid height `B`");
+        assert_eq!(
+            result.position(),
+            Some(crate::Position { line: 1, column: 3 })
+        );
+    }
+
     #[test]
     fn find_position_with_example_input() {
         let heightmap = vec![
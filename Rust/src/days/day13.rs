@@ -1,65 +1,68 @@
-use std::{io::stdin, process::exit};
-
-use advent_of_code::read_input;
+use crate::{
+    parsing::{parse_all, separated_list1_committed},
+    Error, Solution,
+};
 use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::newline,
-    multi::{separated_list0, separated_list1},
-    sequence::delimited,
-    IResult,
+    branch::alt, bytes::complete::tag, character::complete::newline, multi::separated_list0,
+    sequence::delimited, IResult,
 };
 
-fn main() {
-    let input = read_input(&mut stdin()).unwrap_or_else(|err| {
-        eprintln!("Could not read input: {:?}", err);
-        exit(1);
-    });
-    let (_, packets) = parse_input(&input).unwrap_or_else(|err| {
-        eprintln!("Could not parse input: {:?}", err);
-        exit(2);
-    });
-
-    println!(
-        "Sum of indexes of pairs in right order: {}",
-        pairs_in_right_order(&packets).iter().sum::<usize>()
-    );
-    println!("Decoder key: {}", find_decoder_key(&packets));
+#[derive(Debug, Default)]
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Distress Signal";
+    const EXAMPLE: &'static str = include_str!("../../inputs/day13_example.txt");
+
+    type Input = Vec<Vec<PacketElement>>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        let packets = parse_all(input, parse_input)?;
+
+        Ok(packets)
+    }
+
+    fn part_one(&self, packets: &Self::Input) -> Result<Self::PartOne, Error> {
+        Ok(pairs_in_right_order(packets).iter().sum())
+    }
+
+    fn part_two(&self, packets: &Self::Input) -> Result<Self::PartTwo, Error> {
+        Ok(find_decoder_key(packets))
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-enum PacketElement {
+pub enum PacketElement {
     List(Vec<PacketElement>),
     Number(u32),
 }
 
 impl PartialOrd for PacketElement {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PacketElement {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         match (self, other) {
-            (PacketElement::List(list_1), PacketElement::List(list_2)) => {
-                list_1.partial_cmp(list_2)
-            }
+            (PacketElement::List(list_1), PacketElement::List(list_2)) => list_1.cmp(list_2),
             (PacketElement::List(list), PacketElement::Number(other_val)) => {
                 let other_list = &vec![PacketElement::Number(*other_val)];
-                list.partial_cmp(other_list)
+                list.cmp(other_list)
             }
             (PacketElement::Number(val), PacketElement::List(other_list)) => {
                 let list = &vec![PacketElement::Number(*val)];
-                list.partial_cmp(other_list)
-            }
-            (PacketElement::Number(val), PacketElement::Number(other_val)) => {
-                val.partial_cmp(other_val)
+                list.cmp(other_list)
             }
+            (PacketElement::Number(val), PacketElement::Number(other_val)) => val.cmp(other_val),
         }
     }
 }
 
-impl Ord for PacketElement {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.partial_cmp(other).unwrap()
-    }
-}
-
 fn pairs_in_right_order(packets: &[Vec<PacketElement>]) -> Vec<usize> {
     packets
         .iter()
@@ -100,11 +103,11 @@ fn find_decoder_key(packets: &[Vec<PacketElement>]) -> usize {
 }
 
 fn parse_input(input: &str) -> IResult<&str, Vec<Vec<PacketElement>>> {
-    separated_list1(tag("\n\n"), parse_pairs)(input)
+    separated_list1_committed(tag("\n\n"), parse_pairs)(input)
 }
 
 fn parse_pairs(input: &str) -> IResult<&str, Vec<PacketElement>> {
-    separated_list1(newline, parse_list)(input)
+    separated_list1_committed(newline, parse_list)(input)
 }
 
 fn parse_number(input: &str) -> IResult<&str, PacketElement> {
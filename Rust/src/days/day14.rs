@@ -0,0 +1,317 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    config::{parse_value, unknown_parameter},
+    parsing::{parse_all, separated_list1_committed},
+    Error, ErrorKind, Solution,
+};
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, line_ending},
+    sequence::separated_pair,
+    IResult, Parser, ToUsize,
+};
+
+/// Points of the cave taken by rocks or by sand at rest.
+type Cave = BTreeSet<(usize, usize)>;
+
+#[derive(Debug)]
+pub struct Day14 {
+    /// Point where the sand comes from.
+    pub sand_source: (usize, usize),
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Day14 {
+            sand_source: (500, 0),
+        }
+    }
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Regolith Reservoir";
+    const EXAMPLE: &'static str = include_str!("../../inputs/day14_example.txt");
+
+    type Input = BTreeSet<(usize, usize)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        let points = parse_all(input, parse_input)?;
+
+        Ok(points)
+    }
+
+    fn part_one(&self, points: &Self::Input) -> Result<Self::PartOne, Error> {
+        let (mut points, source, floor) = prepare_cave(points, self.sand_source)?;
+
+        Ok(process_sand(&mut points, source, floor - 2))
+    }
+
+    fn part_two(&self, points: &Self::Input) -> Result<Self::PartTwo, Error> {
+        let (mut points, source, floor) = prepare_cave(points, self.sand_source)?;
+
+        Ok(process_sand_with_floor(&mut points, source, floor))
+    }
+
+    fn configure(&mut self, name: &str, value: Option<&str>) -> Result<(), Error> {
+        match name {
+            "source" => {
+                let (x, y) = value
+                    .and_then(|value| value.split_once(','))
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Config,
+                            format!("expected a value like `500,0` for parameter `{}`", name),
+                        )
+                    })?;
+
+                self.sand_source = (parse_value(name, Some(x))?, parse_value(name, Some(y))?);
+            }
+            _ => return Err(unknown_parameter(name, &["source"])),
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that sand can fall from the `source`, which must be above the floor
+/// and not on a rock. The rocks and the source are moved to the right when
+/// needed, so the sand never reaches a negative `x` before the floor, and
+/// returned with the depth of the floor.
+fn prepare_cave(
+    points: &Cave,
+    source: (usize, usize),
+) -> Result<(Cave, (usize, usize), usize), Error> {
+    let floor = points.iter().map(|(_, y)| y).max().cloned().unwrap_or(0) + 2;
+
+    if source.1 >= floor {
+        return Err(Error::new(
+            ErrorKind::Config,
+            format!(
+                "sand source {},{} is not above the floor at depth {}",
+                source.0, source.1, floor
+            ),
+        ));
+    }
+    if points.contains(&source) {
+        return Err(Error::new(
+            ErrorKind::Config,
+            format!("sand source {},{} is on a rock", source.0, source.1),
+        ));
+    }
+
+    let offset = (floor - source.1).saturating_sub(source.0);
+    let points = points.iter().map(|&(x, y)| (x + offset, y)).collect();
+
+    Ok((points, (source.0 + offset, source.1), floor))
+}
+
+fn process_sand(
+    points_set: &mut BTreeSet<(usize, usize)>,
+    source: (usize, usize),
+    maximum_depth: usize,
+) -> usize {
+    let initial_size = points_set.len();
+    let mut sand = source;
+
+    while sand.1 <= maximum_depth {
+        if !points_set.contains(&(sand.0, sand.1 + 1)) {
+            sand = (sand.0, sand.1 + 1);
+        } else if !points_set.contains(&(sand.0 - 1, sand.1 + 1)) {
+            sand = (sand.0 - 1, sand.1 + 1);
+        } else if !points_set.contains(&(sand.0 + 1, sand.1 + 1)) {
+            sand = (sand.0 + 1, sand.1 + 1);
+        } else {
+            points_set.insert(sand);
+            sand = source;
+        }
+    }
+
+    points_set.len() - initial_size
+}
+
+fn process_sand_with_floor(
+    points_set: &mut BTreeSet<(usize, usize)>,
+    source: (usize, usize),
+    floor: usize,
+) -> usize {
+    let initial_size = points_set.len();
+    let mut sand = source;
+
+    while !points_set.contains(&source) {
+        if sand.1 + 1 != floor && !points_set.contains(&(sand.0, sand.1 + 1)) {
+            sand = (sand.0, sand.1 + 1);
+        } else if sand.1 + 1 != floor && !points_set.contains(&(sand.0 - 1, sand.1 + 1)) {
+            sand = (sand.0 - 1, sand.1 + 1);
+        } else if sand.1 + 1 != floor && !points_set.contains(&(sand.0 + 1, sand.1 + 1)) {
+            sand = (sand.0 + 1, sand.1 + 1);
+        } else {
+            points_set.insert(sand);
+            sand = source;
+        }
+    }
+
+    points_set.len() - initial_size
+}
+
+fn parse_input(input: &str) -> IResult<&str, BTreeSet<(usize, usize)>> {
+    let (input, paths) = separated_list1_committed(
+        line_ending,
+        separated_list1_committed(
+            tag(" -> "),
+            separated_pair(
+                complete::u32.map(|n| n.to_usize()),
+                tag(","),
+                complete::u32.map(|n| n.to_usize()),
+            ),
+        ),
+    )(input)?;
+
+    Ok((input, get_points_set_from_paths(paths)))
+}
+
+fn get_points_set_from_paths(paths: Vec<Vec<(usize, usize)>>) -> BTreeSet<(usize, usize)> {
+    let mut points = BTreeSet::new();
+
+    for path in paths {
+        for ((x1, y1), (x2, y2)) in path.into_iter().tuple_windows() {
+            let x_range = x1.min(x2)..=x1.max(x2);
+            let y_range = y1.min(y2)..=y1.max(y2);
+
+            for p in x_range.cartesian_product(y_range) {
+                points.insert(p);
+            }
+        }
+    }
+
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    const EXAMPLE_INPUT: &str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    #[test]
+    fn parse_input_with_example_input() {
+        let (input, result) = parse_input(EXAMPLE_INPUT).unwrap();
+        let expected = example_input_set();
+
+        assert_eq!(result, expected);
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn process_sand_with_example_input() {
+        let mut points_set = example_input_set();
+        let result = process_sand(&mut points_set, (500, 0), 9);
+
+        assert_eq!(result, 24);
+    }
+
+    #[test]
+    fn process_sand_with_floor_with_example_input() {
+        let mut points_set = example_input_set();
+        let result = process_sand_with_floor(&mut points_set, (500, 0), 11);
+
+        assert_eq!(result, 93);
+    }
+
+    #[test]
+    fn process_sand_with_source_off_the_rocks() {
+        let mut points_set = example_input_set();
+        let result = process_sand(&mut points_set, (494, 0), 9);
+
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn solve_with_source_at_left_edge() {
+        let mut day = Day14::default();
+        day.configure("source", Some("0,0")).unwrap();
+        let points = BTreeSet::from([(0, 2), (1, 2)]);
+
+        assert_eq!(day.part_one(&points).unwrap(), 0);
+        assert_eq!(day.part_two(&points).unwrap(), 14);
+    }
+
+    #[test]
+    fn solve_without_rocks() {
+        let day = Day14::default();
+        let points = day.parse("500,5").unwrap();
+
+        assert!(points.is_empty());
+        assert_eq!(day.part_one(&points).unwrap(), 0);
+        assert_eq!(day.part_two(&points).unwrap(), 4);
+    }
+
+    #[test]
+    fn solve_with_invalid_source() {
+        let points = example_input_set();
+        let test_cases = [
+            (
+                (500, 11),
+                "sand source 500,11 is not above the floor at depth 11",
+            ),
+            (
+                (500, 20),
+                "sand source 500,20 is not above the floor at depth 11",
+            ),
+            ((498, 5), "sand source 498,5 is on a rock"),
+        ];
+
+        for (source, message) in test_cases {
+            let day = Day14 {
+                sand_source: source,
+            };
+            let part_one = day.part_one(&points).unwrap_err();
+            let part_two = day.part_two(&points).unwrap_err();
+
+            assert_eq!(part_one.kind(), ErrorKind::Config);
+            assert_eq!(part_one.message(), message);
+            assert_eq!(part_two.message(), message);
+        }
+    }
+
+    #[test]
+    fn configure_with_sand_source() {
+        let mut day = Day14::default();
+        day.configure("source", Some("494,2")).unwrap();
+
+        assert_eq!(day.sand_source, (494, 2));
+        assert!(day.configure("source", Some("494")).is_err());
+        assert!(day.configure("source", Some("x,2")).is_err());
+    }
+
+    fn example_input_set() -> BTreeSet<(usize, usize)> {
+        BTreeSet::from([
+            (498, 4),
+            (498, 5),
+            (498, 6),
+            (497, 6),
+            (496, 6),
+            (503, 4),
+            (502, 4),
+            (502, 5),
+            (502, 6),
+            (502, 7),
+            (502, 8),
+            (502, 9),
+            (501, 9),
+            (500, 9),
+            (499, 9),
+            (498, 9),
+            (497, 9),
+            (496, 9),
+            (495, 9),
+            (494, 9),
+        ])
+    }
+}
@@ -1,48 +1,87 @@
-use std::{io::stdin, process::exit};
-
-use advent_of_code::read_input;
+use crate::{
+    config::{parse_value, unknown_parameter},
+    parsing::{parse_all, separated_list1_committed},
+    Error, Solution,
+};
 use itertools::Itertools;
 use nom::{
     bytes::complete::tag,
     character::complete::{self, line_ending},
-    multi::separated_list0,
     sequence::{preceded, separated_pair},
     IResult, Parser,
 };
 
 #[derive(Debug)]
-struct Sensor {
+pub struct Sensor {
     x: i32,
     y: i32,
     closest_beacon_distance: i32,
 }
 
 #[derive(Debug)]
-struct Beacon {
+pub struct Beacon {
     x: i32,
     y: i32,
 }
 
-fn main() {
-    let input = read_input(&mut stdin()).unwrap_or_else(|err| {
-        eprintln!("Could not read input: {:?}", err);
-        exit(1);
-    });
-    let (_, sensors) = parse_input(&input).unwrap_or_else(|err| {
-        eprintln!("Could not parse input: {:?}", err);
-        exit(2);
-    });
-
-    let positions_without_beacons = count_positions_without_beacons(&sensors, 2_000_000);
-    println!(
-        "Number of positions without beacons: {}",
-        positions_without_beacons
-    );
-
-    let distress_beacon_frequency = find_distress_beacon_frequency(&sensors, 4_000_000);
-    println!("Distress beacon frequency: {}", distress_beacon_frequency);
+#[derive(Debug)]
+pub struct Day15 {
+    /// Row where positions without beacons are counted in part one.
+    pub target_row: i32,
+    /// Largest coordinate the distress beacon can be at in part two.
+    pub search_limit: i32,
 }
 
+impl Default for Day15 {
+    fn default() -> Self {
+        Day15 {
+            target_row: 2_000_000,
+            search_limit: 4_000_000,
+        }
+    }
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Beacon Exclusion Zone";
+    const EXAMPLE: &'static str = include_str!("../../inputs/day15_example.txt");
+    const EXAMPLE_PARAMS: &'static [(&'static str, &'static str)] =
+        &[("row", "10"), ("limit", "20")];
+
+    type Input = Vec<(Sensor, Beacon)>;
+    type PartOne = usize;
+    type PartTwo = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        let sensors = parse_all(input, parse_input)?;
+
+        Ok(sensors)
+    }
+
+    fn part_one(&self, sensors: &Self::Input) -> Result<Self::PartOne, Error> {
+        Ok(count_positions_without_beacons(sensors, self.target_row))
+    }
+
+    fn part_two(&self, sensors: &Self::Input) -> Result<Self::PartTwo, Error> {
+        find_distress_beacon_frequency(sensors, self.search_limit)
+            .ok_or_else(|| Error::solve("no position available for the distress beacon"))
+    }
+
+    fn configure(&mut self, name: &str, value: Option<&str>) -> Result<(), Error> {
+        match name {
+            "row" => self.target_row = parse_value(name, value)?,
+            "limit" => self.search_limit = parse_value(name, value)?,
+            _ => return Err(unknown_parameter(name, &["row", "limit"])),
+        }
+
+        Ok(())
+    }
+}
+
+/// The tuning frequency of the distress beacon is its `x` coordinate times
+/// this value plus its `y` coordinate, whatever the search limit is.
+const TUNING_FREQUENCY_FACTOR: i64 = 4_000_000;
+
 fn count_positions_without_beacons(sensors: &[(Sensor, Beacon)], target_row: i32) -> usize {
     sensors
         .iter()
@@ -64,7 +103,7 @@ fn count_positions_without_beacons(sensors: &[(Sensor, Beacon)], target_row: i32
         .count()
 }
 
-fn find_distress_beacon_frequency(sensors: &[(Sensor, Beacon)], limit: i32) -> i64 {
+fn find_distress_beacon_frequency(sensors: &[(Sensor, Beacon)], limit: i32) -> Option<i64> {
     let position = sensors
         .iter()
         .flat_map(|(sensor, _)| {
@@ -87,10 +126,7 @@ fn find_distress_beacon_frequency(sensors: &[(Sensor, Beacon)], limit: i32) -> i
         })
         .find(|(x, y)| is_available_position(sensors, *x, *y));
 
-    match position {
-        Some((x, y)) => x as i64 * 4_000_000 + y as i64,
-        None => panic!("No empty position found!"),
-    }
+    position.map(|(x, y)| x as i64 * TUNING_FREQUENCY_FACTOR + y as i64)
 }
 
 fn is_available_position(sensors: &[(Sensor, Beacon)], x: i32, y: i32) -> bool {
@@ -102,7 +138,7 @@ fn is_available_position(sensors: &[(Sensor, Beacon)], x: i32, y: i32) -> bool {
 }
 
 fn parse_input(input: &str) -> IResult<&str, Vec<(Sensor, Beacon)>> {
-    separated_list0(line_ending, parse_line)(input)
+    separated_list1_committed(line_ending, parse_line)(input)
 }
 
 fn parse_line(input: &str) -> IResult<&str, (Sensor, Beacon)> {
@@ -139,6 +175,7 @@ fn manhattan_distance((x1, y1): (i32, i32), (x2, y2): (i32, i32)) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Position;
 
     const INPUT: &str = include_str!("../../inputs/day15_example.txt");
 
@@ -150,6 +187,21 @@ mod tests {
         assert_eq!(result.len(), 14);
     }
 
+    #[test]
+    fn parse_with_invalid_position() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=1O, y=16: closest beacon is at x=10, y=16";
+        let result = Day15::default().parse(input).unwrap_err();
+
+        assert_eq!(
+            result.position(),
+            Some(Position {
+                line: 2,
+                column: 14
+            })
+        );
+    }
+
     #[test]
     fn count_positions_without_beacons_with_example() {
         let (_, sensors) = parse_input(INPUT).unwrap();
@@ -163,6 +215,17 @@ mod tests {
         let (_, sensors) = parse_input(INPUT).unwrap();
         let result = find_distress_beacon_frequency(&sensors, 20);
 
-        assert_eq!(result, 56000011);
+        assert_eq!(result, Some(56000011));
+    }
+
+    #[test]
+    fn solve_with_example_parameters() {
+        let mut day = Day15::default();
+        day.configure("row", Some("10")).unwrap();
+        day.configure("limit", Some("20")).unwrap();
+        let sensors = day.parse(INPUT).unwrap();
+
+        assert_eq!(day.part_one(&sensors).unwrap(), 26);
+        assert_eq!(day.part_two(&sensors).unwrap(), 56000011);
     }
 }
@@ -0,0 +1,81 @@
+//! Solutions for each day of Advent of Code 2022.
+
+use crate::Puzzle;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+
+/// Returns the solutions of every day, ordered by day.
+pub fn puzzles() -> Vec<Box<dyn Puzzle>> {
+    vec![
+        Box::new(day01::Day01::default()),
+        Box::new(day02::Day02::default()),
+        Box::new(day03::Day03::default()),
+        Box::new(day04::Day04::default()),
+        Box::new(day05::Day05::default()),
+        Box::new(day06::Day06),
+        Box::new(day07::Day07::default()),
+        Box::new(day08::Day08),
+        Box::new(day09::Day09),
+        Box::new(day10::Day10::default()),
+        Box::new(day11::Day11::default()),
+        Box::new(day12::Day12),
+        Box::new(day13::Day13),
+        Box::new(day14::Day14::default()),
+        Box::new(day15::Day15::default()),
+    ]
+}
+
+/// Finds the solution for the given `day`, if it has been solved.
+pub fn find_puzzle(day: u8) -> Option<Box<dyn Puzzle>> {
+    puzzles().into_iter().find(|puzzle| puzzle.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_puzzle_with_existing_day() {
+        let puzzle = find_puzzle(12).unwrap();
+
+        assert_eq!(puzzle.day(), 12);
+        assert_eq!(puzzle.title(), "Hill Climbing Algorithm");
+    }
+
+    #[test]
+    fn puzzles_are_ordered_by_day() {
+        let days: Vec<u8> = puzzles().iter().map(|puzzle| puzzle.day()).collect();
+
+        assert_eq!(days, (1..=15).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn every_example_can_be_solved() {
+        for mut puzzle in puzzles() {
+            puzzle.configure_example().unwrap();
+            let result = puzzle.solve(puzzle.example(), None);
+
+            assert!(result.is_ok(), "day {}: {:?}", puzzle.day(), result);
+        }
+    }
+
+    #[test]
+    fn find_puzzle_with_missing_day() {
+        assert!(find_puzzle(0).is_none());
+        assert!(find_puzzle(25).is_none());
+    }
+}
@@ -0,0 +1,215 @@
+use std::{fmt, io};
+
+type Source = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// Line and column of the puzzle input, both starting at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The puzzle input could not be read.
+    Io,
+    /// The puzzle input is not valid for the puzzle.
+    Parse,
+    /// The puzzle input is valid, but the puzzle could not be solved with it.
+    Solve,
+    /// A parameter of the puzzle is unknown or has an invalid value.
+    Config,
+}
+
+/// Error returned by every solution. Besides a human-readable message it keeps
+/// the day of the puzzle, the position of the bad input and the underlying
+/// error, when those are known.
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    message: String,
+    day: Option<u8>,
+    position: Option<Position>,
+    snippet: Option<String>,
+    source: Option<Source>,
+}
+
+impl Error {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Error {
+        Error {
+            kind,
+            message: message.into(),
+            day: None,
+            position: None,
+            snippet: None,
+            source: None,
+        }
+    }
+
+    pub fn parse(message: impl Into<String>) -> Error {
+        Error::new(ErrorKind::Parse, message)
+    }
+
+    pub fn solve(message: impl Into<String>) -> Error {
+        Error::new(ErrorKind::Solve, message)
+    }
+
+    /// Sets the day of the puzzle, unless one was already set.
+    pub fn with_day(mut self, day: u8) -> Error {
+        self.day.get_or_insert(day);
+        self
+    }
+
+    /// Sets the position of the input where the error happened.
+    pub fn at(mut self, line: usize, column: usize) -> Error {
+        self.position = Some(Position { line, column });
+        self
+    }
+
+    /// Moves the position of the error to the given `line`, keeping its
+    /// column. Used for errors found when parsing a single line of the input.
+    pub fn on_line(mut self, line: usize) -> Error {
+        if let Some(position) = &mut self.position {
+            position.line = line;
+        }
+        self
+    }
+
+    /// Sets the position of the error from the byte `offset` of the `input`,
+    /// keeping the line where it happened so it can be shown with the error.
+    pub fn at_offset(mut self, input: &str, offset: usize) -> Error {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let line = input[..offset].matches('\n').count() + 1;
+        let column = input[line_start..offset].chars().count() + 1;
+
+        self.snippet = Some(input[line_start..line_end].trim_end().to_string());
+        self.at(line, column)
+    }
+
+    pub fn with_source(mut self, source: impl Into<Source>) -> Error {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    pub fn position(&self) -> Option<Position> {
+        self.position
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}: ", day)?;
+        }
+
+        if let Some(position) = self.position {
+            write!(f, "{}: ", position)?;
+        }
+
+        write!(f, "{}", self.message)?;
+
+        if let (Some(position), Some(snippet)) = (self.position, &self.snippet) {
+            let gutter = " ".repeat(position.line.to_string().len());
+
+            write!(f, "\n{} | {}", position.line, snippet)?;
+            write!(f, "\n{} | {}^", gutter, " ".repeat(position.column - 1))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn std::error::Error + 'static))
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::new(ErrorKind::Io, "could not read input").with_source(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+
+    use super::*;
+
+    #[test]
+    fn display_with_message_only() {
+        let error = Error::parse("invalid shape");
+
+        assert_eq!(error.to_string(), "invalid shape");
+    }
+
+    #[test]
+    fn display_with_day_and_position() {
+        let error = Error::parse("invalid shape").at(3, 5).with_day(2);
+
+        assert_eq!(error.to_string(), "day 2: line 3, column 5: invalid shape");
+    }
+
+    #[test]
+    fn display_with_snippet() {
+        let error = Error::parse("invalid instruction").at_offset("noop\naddx 3\nadx 5\n", 12);
+
+        assert_eq!(error.position(), Some(Position { line: 3, column: 1 }));
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: invalid instruction\n3 | adx 5\n  | ^"
+        );
+    }
+
+    #[test]
+    fn on_line_keeps_column_and_snippet() {
+        let error = Error::parse("invalid instruction")
+            .at_offset("adx 5", 0)
+            .on_line(12);
+
+        assert_eq!(
+            error.to_string(),
+            "line 12, column 1: invalid instruction\n12 | adx 5\n   | ^"
+        );
+    }
+
+    #[test]
+    fn with_day_keeps_first_day() {
+        let error = Error::solve("no path found").with_day(12).with_day(1);
+
+        assert_eq!(error.day(), Some(12));
+    }
+
+    #[test]
+    fn from_io_error_keeps_source() {
+        let error = Error::from(io::Error::new(io::ErrorKind::NotFound, "missing file"));
+
+        assert_eq!(error.kind(), ErrorKind::Io);
+        assert_eq!(error.source().unwrap().to_string(), "missing file");
+    }
+}
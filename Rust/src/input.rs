@@ -0,0 +1,98 @@
+//! Helpers to read the puzzle input as it comes, without keeping all of it
+//! in memory.
+
+use std::io::BufRead;
+
+use crate::Error;
+
+/// Iterates over the lines of the `reader`, along with their line number
+/// starting at 1. Line endings, including `\r\n`, are removed.
+pub fn lines(
+    reader: &mut dyn BufRead,
+) -> impl Iterator<Item = Result<(usize, String), Error>> + '_ {
+    BufRead::lines(reader).enumerate().map(|(i, line)| {
+        let mut line = line?;
+        if line.ends_with('\r') {
+            line.pop();
+        }
+
+        Ok((i + 1, line))
+    })
+}
+
+/// Iterates over the bytes of the `reader`, reading them a chunk at a time.
+pub fn bytes(reader: &mut dyn BufRead) -> Bytes<'_> {
+    Bytes {
+        reader,
+        chunk: vec![],
+        position: 0,
+    }
+}
+
+/// Iterator over the bytes of a reader, see [`bytes`].
+pub struct Bytes<'a> {
+    reader: &'a mut dyn BufRead,
+    chunk: Vec<u8>,
+    position: usize,
+}
+
+impl Iterator for Bytes<'_> {
+    type Item = Result<u8, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position == self.chunk.len() {
+            let chunk = match self.reader.fill_buf() {
+                Ok(chunk) => chunk,
+                Err(err) => return Some(Err(err.into())),
+            };
+
+            self.chunk.clear();
+            self.chunk.extend_from_slice(chunk);
+            self.reader.consume(self.chunk.len());
+            self.position = 0;
+        }
+
+        let byte = *self.chunk.get(self.position)?;
+        self.position += 1;
+
+        Some(Ok(byte))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    #[test]
+    fn lines_with_crlf_line_endings() {
+        let result: Vec<(usize, String)> = lines(&mut "R 4\r\nU 4\n\nL 3".as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let expected = vec![
+            (1, "R 4".to_string()),
+            (2, "U 4".to_string()),
+            (3, String::new()),
+            (4, "L 3".to_string()),
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn bytes_across_chunks() {
+        let mut reader = BufReader::with_capacity(2, "mjqjp".as_bytes());
+        let result: Vec<u8> = bytes(&mut reader).collect::<Result<_, _>>().unwrap();
+
+        assert_eq!(result, b"mjqjp");
+    }
+
+    #[test]
+    fn lines_with_invalid_utf8() {
+        let mut reader: &[u8] = b"R 4\n\xff\n";
+        let result: Result<Vec<(usize, String)>, Error> = lines(&mut reader).collect();
+
+        assert!(result.is_err());
+    }
+}
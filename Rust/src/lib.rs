@@ -1,16 +1,381 @@
-use std::io::Read;
+use std::{
+    fmt::Display,
+    hint::black_box,
+    io::{BufRead, Read},
+    time::Instant,
+};
 
-#[derive(Debug)]
-pub enum Error {
-    FailedToReadInput,
-}
+use bench::{measure, Benchmark, Timings};
+use config::unknown_parameter;
+use report::{unknown_report, Report};
+
+pub mod bench;
+pub mod config;
+pub mod days;
+mod error;
+pub mod input;
+pub mod parsing;
+pub mod report;
+
+pub use error::{Error, ErrorKind, Position};
 
-pub fn read_input(reader: &mut impl Read) -> Result<String, Error> {
+pub fn read_input<R: Read + ?Sized>(reader: &mut R) -> Result<String, Error> {
     let mut buffer = String::new();
 
-    reader
-        .read_to_string(&mut buffer)
-        .map_err(|_| Error::FailedToReadInput)?;
+    reader.read_to_string(&mut buffer)?;
 
     Ok(buffer)
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+/// Solution for the puzzle of a single day. The input is parsed once and the
+/// parsed value is then shared by both parts of the puzzle.
+pub trait Solution {
+    /// Day of the puzzle, from 1 to 25.
+    const DAY: u8;
+    /// Title of the puzzle, as shown on the Advent of Code website.
+    const TITLE: &'static str;
+    /// Example input given in the description of the puzzle.
+    const EXAMPLE: &'static str;
+    /// Parameters the example is solved with, as pairs of name and value for
+    /// [`Solution::configure`], for puzzles whose example does not use the
+    /// same parameters as the real input.
+    const EXAMPLE_PARAMS: &'static [(&'static str, &'static str)] = &[];
+    /// Names of the reports of the puzzle, see [`Solution::report`].
+    const REPORTS: &'static [&'static str] = &[];
+
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error>;
+
+    fn part_one(&self, input: &Self::Input) -> Result<Self::PartOne, Error>;
+
+    fn part_two(&self, input: &Self::Input) -> Result<Self::PartTwo, Error>;
+
+    /// Solves the given `part`, or both parts when `part` is `None`, reading
+    /// the input from `reader`. By default the whole input is read and parsed
+    /// first. Puzzles whose input can be processed as it is read override this
+    /// so they do not need to keep the whole input in memory.
+    #[allow(clippy::type_complexity)]
+    fn stream(
+        &self,
+        reader: &mut dyn BufRead,
+        part: Option<Part>,
+    ) -> Result<(Option<Self::PartOne>, Option<Self::PartTwo>), Error> {
+        let input = self.parse(&read_input(reader)?)?;
+        let part_one = match part {
+            Some(Part::Two) => None,
+            _ => Some(self.part_one(&input)?),
+        };
+        let part_two = match part {
+            Some(Part::One) => None,
+            _ => Some(self.part_two(&input)?),
+        };
+
+        Ok((part_one, part_two))
+    }
+
+    /// Sets the parameter of the puzzle with the given `name` to `value`.
+    /// Puzzles without parameters reject every name.
+    fn configure(&mut self, name: &str, value: Option<&str>) -> Result<(), Error> {
+        let _ = value;
+
+        Err(unknown_parameter(name, &[]))
+    }
+
+    /// Builds the report with the given `name`, one of [`Solution::REPORTS`],
+    /// with details of the solution that do not fit in the answers.
+    fn report(&self, name: &str, input: &Self::Input) -> Result<Report, Error> {
+        let _ = input;
+
+        Err(unknown_report(name, Self::REPORTS))
+    }
+}
+
+/// Answers for the parts of a puzzle that were solved, along with the time
+/// taken by each stage.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+    pub timings: Timings,
+}
+
+/// Object safe version of [`Solution`], used to pick the solution of a day at
+/// runtime. It is implemented for every [`Solution`].
+pub trait Puzzle {
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    /// Example input of the puzzle, see [`Solution::EXAMPLE`].
+    fn example(&self) -> &'static str;
+
+    /// Sets the parameters the example is solved with, see
+    /// [`Solution::EXAMPLE_PARAMS`].
+    fn configure_example(&mut self) -> Result<(), Error>;
+
+    /// Sets a parameter of the puzzle, see [`Solution::configure`]. Errors are
+    /// tagged with the day of the puzzle.
+    fn configure(&mut self, name: &str, value: Option<&str>) -> Result<(), Error>;
+
+    /// Names of the reports of the puzzle, see [`Solution::REPORTS`].
+    fn reports(&self) -> &'static [&'static str];
+
+    /// Parses the `input` and builds the report with the given `name`, see
+    /// [`Solution::report`]. Errors are tagged with the day of the puzzle.
+    fn report(&self, name: &str, input: &str) -> Result<Report, Error>;
+
+    /// Parses the `input` and solves the given `part`, or both parts when
+    /// `part` is `None`. Errors are tagged with the day of the puzzle.
+    fn solve(&self, input: &str, part: Option<Part>) -> Result<Answers, Error>;
+
+    /// Solves the given `part`, or both parts when `part` is `None`, reading
+    /// the input from `reader`, see [`Solution::stream`]. Reading and solving
+    /// happen together, so their whole time is reported as parsing time.
+    fn solve_reader(&self, reader: &mut dyn BufRead, part: Option<Part>) -> Result<Answers, Error>;
+
+    /// Parses the `input` and solves the given `part`, or both parts when
+    /// `part` is `None`, running each stage `iterations` times. The timings of
+    /// the returned answers are the median of every run.
+    fn bench(
+        &self,
+        input: &str,
+        part: Option<Part>,
+        iterations: usize,
+    ) -> Result<(Answers, Benchmark), Error>;
+}
+
+impl<S: Solution> Puzzle for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn example(&self) -> &'static str {
+        S::EXAMPLE
+    }
+
+    fn configure_example(&mut self) -> Result<(), Error> {
+        for (name, value) in S::EXAMPLE_PARAMS {
+            Puzzle::configure(self, name, Some(value))?;
+        }
+
+        Ok(())
+    }
+
+    fn configure(&mut self, name: &str, value: Option<&str>) -> Result<(), Error> {
+        Solution::configure(self, name, value).map_err(|err| err.with_day(S::DAY))
+    }
+
+    fn reports(&self) -> &'static [&'static str] {
+        S::REPORTS
+    }
+
+    fn report(&self, name: &str, input: &str) -> Result<Report, Error> {
+        if !S::REPORTS.contains(&name) {
+            return Err(unknown_report(name, S::REPORTS).with_day(S::DAY));
+        }
+
+        self.parse(input)
+            .and_then(|input| Solution::report(self, name, &input))
+            .map_err(|err| err.with_day(S::DAY))
+    }
+
+    fn solve(&self, input: &str, part: Option<Part>) -> Result<Answers, Error> {
+        let solve = || {
+            let start = Instant::now();
+            let input = self.parse(input)?;
+            let mut answers = Answers::default();
+            answers.timings.parse = start.elapsed();
+
+            if part != Some(Part::Two) {
+                let start = Instant::now();
+                let answer = self.part_one(&input)?;
+                answers.timings.part_one = Some(start.elapsed());
+                answers.part_one = Some(answer.to_string());
+            }
+
+            if part != Some(Part::One) {
+                let start = Instant::now();
+                let answer = self.part_two(&input)?;
+                answers.timings.part_two = Some(start.elapsed());
+                answers.part_two = Some(answer.to_string());
+            }
+
+            Ok(answers)
+        };
+
+        solve().map_err(|err: Error| err.with_day(S::DAY))
+    }
+
+    fn solve_reader(&self, reader: &mut dyn BufRead, part: Option<Part>) -> Result<Answers, Error> {
+        let start = Instant::now();
+        let (part_one, part_two) = self
+            .stream(reader, part)
+            .map_err(|err| err.with_day(S::DAY))?;
+        let mut answers = Answers {
+            part_one: part_one.map(|answer| answer.to_string()),
+            part_two: part_two.map(|answer| answer.to_string()),
+            ..Answers::default()
+        };
+        answers.timings.parse = start.elapsed();
+
+        Ok(answers)
+    }
+
+    fn bench(
+        &self,
+        input: &str,
+        part: Option<Part>,
+        iterations: usize,
+    ) -> Result<(Answers, Benchmark), Error> {
+        let bench = || {
+            let (input, parse) = measure(iterations, || self.parse(black_box(input)))?;
+            let mut answers = Answers::default();
+            let mut benchmark = Benchmark {
+                iterations,
+                parse,
+                part_one: None,
+                part_two: None,
+            };
+
+            if part != Some(Part::Two) {
+                let (answer, stats) = measure(iterations, || self.part_one(black_box(&input)))?;
+                answers.part_one = Some(answer.to_string());
+                benchmark.part_one = Some(stats);
+            }
+
+            if part != Some(Part::One) {
+                let (answer, stats) = measure(iterations, || self.part_two(black_box(&input)))?;
+                answers.part_two = Some(answer.to_string());
+                benchmark.part_two = Some(stats);
+            }
+
+            answers.timings = Timings {
+                parse: parse.median,
+                part_one: benchmark.part_one.map(|stats| stats.median),
+                part_two: benchmark.part_two.map(|stats| stats.median),
+            };
+
+            Ok((answers, benchmark))
+        };
+
+        bench().map_err(|err: Error| err.with_day(S::DAY))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        const TITLE: &'static str = "Sum";
+        const EXAMPLE: &'static str = "1\n2\n3\n";
+
+        type Input = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = u32;
+
+        fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+            input
+                .lines()
+                .enumerate()
+                .map(|(i, line)| {
+                    line.parse()
+                        .map_err(|err| Error::parse("invalid number").at(i + 1, 1).with_source(err))
+                })
+                .collect()
+        }
+
+        fn part_one(&self, input: &Self::Input) -> Result<Self::PartOne, Error> {
+            Ok(input.iter().sum())
+        }
+
+        fn part_two(&self, input: &Self::Input) -> Result<Self::PartTwo, Error> {
+            Ok(input.iter().product())
+        }
+    }
+
+    #[test]
+    fn solve_with_both_parts() {
+        let result = Sum.solve("2\n3\n4", None).unwrap();
+
+        assert_eq!(result.part_one, Some("9".to_string()));
+        assert_eq!(result.part_two, Some("24".to_string()));
+        assert!(result.timings.part_one.is_some());
+        assert!(result.timings.part_two.is_some());
+    }
+
+    #[test]
+    fn solve_with_single_part() {
+        let result = Sum.solve("2\n3\n4", Some(Part::Two)).unwrap();
+
+        assert_eq!(result.part_one, None);
+        assert_eq!(result.part_two, Some("24".to_string()));
+        assert_eq!(result.timings.part_one, None);
+    }
+
+    #[test]
+    fn solve_reader_with_single_part() {
+        let result = Sum
+            .solve_reader(&mut "2\n3\n4".as_bytes(), Some(Part::One))
+            .unwrap();
+
+        assert_eq!(result.part_one, Some("9".to_string()));
+        assert_eq!(result.part_two, None);
+    }
+
+    #[test]
+    fn bench_with_single_part() {
+        let (answers, result) = Sum.bench("2\n3\n4", Some(Part::One), 3).unwrap();
+
+        assert_eq!(answers.part_one, Some("9".to_string()));
+        assert_eq!(result.iterations, 3);
+        assert!(result.part_one.is_some());
+        assert!(result.part_two.is_none());
+    }
+
+    #[test]
+    fn solve_with_invalid_input() {
+        let result = Sum.solve("2\nthree", None).unwrap_err();
+
+        assert_eq!(result.kind(), ErrorKind::Parse);
+        assert_eq!(
+            result.to_string(),
+            "day 0: line 2, column 1: invalid number"
+        );
+    }
+
+    #[test]
+    fn configure_without_parameters() {
+        let result = Puzzle::configure(&mut Sum, "rounds", Some("10")).unwrap_err();
+
+        assert_eq!(result.kind(), ErrorKind::Config);
+        assert_eq!(result.day(), Some(0));
+    }
+
+    #[test]
+    fn report_without_reports() {
+        let result = Puzzle::report(&Sum, "ranking", "1\n2").unwrap_err();
+
+        assert_eq!(result.kind(), ErrorKind::Config);
+        assert_eq!(
+            result.to_string(),
+            "day 0: unknown report `ranking`, this puzzle has no reports"
+        );
+    }
+}
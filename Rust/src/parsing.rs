@@ -0,0 +1,135 @@
+//! Helpers to run `nom` parsers over the whole puzzle input.
+
+use nom::{error::ErrorKind as NomErrorKind, IResult, Parser};
+
+use crate::Error;
+
+/// Runs the `parser` over the whole `input`. When parsing fails, the returned
+/// error points to the line and column where the parser stopped. Input left
+/// unconsumed by the parser is also an error, except for trailing whitespace.
+pub fn parse_all<'a, O, P>(input: &'a str, mut parser: P) -> Result<O, Error>
+where
+    P: Parser<&'a str, O, nom::error::Error<&'a str>>,
+{
+    match parser.parse(input) {
+        Ok((remaining, output)) => {
+            let remaining = remaining.trim_start();
+
+            if remaining.is_empty() {
+                Ok(output)
+            } else {
+                Err(Error::parse("unexpected input").at_offset(input, offset(input, remaining)))
+            }
+        }
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            let message = match err.code {
+                NomErrorKind::Eof => "unexpected end of input".to_string(),
+                code => format!("invalid input ({})", code.description().to_lowercase()),
+            };
+
+            Err(Error::parse(message).at_offset(input, offset(input, err.input)))
+        }
+        Err(nom::Err::Incomplete(_)) => {
+            Err(Error::parse("unexpected end of input").at_offset(input, input.len()))
+        }
+    }
+}
+
+/// Like `nom::multi::separated_list1`, but the list only ends before a
+/// separator when the `element` after it fails right at its start. Once an
+/// element has been partly parsed its error is returned as a failure, so
+/// [`parse_all`] points to where the element went wrong instead of to the
+/// start of its line.
+pub fn separated_list1_committed<'a, O, O2, S, P>(
+    mut separator: S,
+    mut element: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    S: Parser<&'a str, O2, nom::error::Error<&'a str>>,
+    P: Parser<&'a str, O, nom::error::Error<&'a str>>,
+{
+    move |input| {
+        let (mut input, first) = element.parse(input)?;
+        let mut elements = vec![first];
+
+        while let Ok((after_separator, _)) = separator.parse(input) {
+            match element.parse(after_separator) {
+                Ok((remaining, value)) => {
+                    elements.push(value);
+                    input = remaining;
+                }
+                Err(nom::Err::Error(err)) if err.input.len() < after_separator.len() => {
+                    return Err(nom::Err::Failure(err));
+                }
+                Err(nom::Err::Error(_)) => break,
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok((input, elements))
+    }
+}
+
+/// Byte offset of `remaining` in the `input` it is a suffix of.
+fn offset(input: &str, remaining: &str) -> usize {
+    input.len() - remaining.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        bytes::complete::tag, character::complete::newline, multi::separated_list1,
+        sequence::separated_pair, IResult,
+    };
+
+    use super::*;
+    use crate::Position;
+
+    fn numbers(input: &str) -> IResult<&str, Vec<u32>> {
+        separated_list1(newline, nom::character::complete::u32)(input)
+    }
+
+    #[test]
+    fn parse_all_with_valid_input() {
+        let result = parse_all("1\n2\n3\n", numbers).unwrap();
+
+        assert_eq!(result, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn parse_all_with_unconsumed_input() {
+        let result = parse_all("1\n2\nthree\n", numbers).unwrap_err();
+
+        assert_eq!(result.position(), Some(Position { line: 3, column: 1 }));
+        assert_eq!(
+            result.to_string(),
+            "line 3, column 1: unexpected input\n3 | three\n  | ^"
+        );
+    }
+
+    #[test]
+    fn separated_list1_committed_with_invalid_element() {
+        let number = nom::character::complete::u32;
+        let parser = separated_list1_committed(newline, separated_pair(number, tag(","), number));
+        let result = parse_all("1,2\n3,x\n5,6", parser).unwrap_err();
+
+        assert_eq!(result.position(), Some(Position { line: 2, column: 3 }));
+        assert_eq!(result.message(), "invalid input (digit)");
+    }
+
+    #[test]
+    fn separated_list1_committed_ends_before_unknown_element() {
+        let mut parser = separated_list1_committed(tag("\n\n"), numbers);
+        let result = parser("1\n2\n\nthree").unwrap();
+
+        assert_eq!(result, ("\n\nthree", vec![vec![1, 2]]));
+    }
+
+    #[test]
+    fn parse_all_with_invalid_input() {
+        let result = parse_all("noop\naddx 3", tag("noop\nadd ")).unwrap_err();
+
+        assert_eq!(result.position(), Some(Position { line: 1, column: 1 }));
+        assert_eq!(result.message(), "invalid input (tag)");
+    }
+}
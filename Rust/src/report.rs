@@ -0,0 +1,149 @@
+//! Reports with details of how a puzzle was solved, besides its answers.
+
+use std::fmt;
+
+use crate::{Error, ErrorKind};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Report {
+    /// Free-form text, such as a drawing.
+    Text(String),
+    /// Rows of values, which can also be written as CSV.
+    Table(Table),
+    /// Drawings of consecutive steps, which can also be played as an animation.
+    Frames(Vec<String>),
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Report::Text(text) => write!(f, "{}", text),
+            Report::Table(table) => write!(f, "{}", table),
+            Report::Frames(frames) => write!(f, "{}", frames.join("\n")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: &[&str]) -> Table {
+        Table {
+            headers: headers.iter().map(|header| header.to_string()).collect(),
+            rows: vec![],
+        }
+    }
+
+    /// Adds a row to the end of the table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row does not have one value for each column.
+    pub fn push(&mut self, row: Vec<String>) {
+        assert_eq!(row.len(), self.headers.len(), "wrong number of values");
+        self.rows.push(row);
+    }
+
+    pub fn headers(&self) -> &[String] {
+        &self.headers
+    }
+
+    pub fn rows(&self) -> &[Vec<String>] {
+        &self.rows
+    }
+}
+
+/// Writes the table with its columns aligned. Columns holding only numbers are
+/// aligned to the right.
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let columns = (0..self.headers.len()).map(|column| {
+            let values = self.rows.iter().map(|row| &row[column]);
+            let width = values
+                .clone()
+                .chain([&self.headers[column]])
+                .map(|value| value.chars().count())
+                .max()
+                .unwrap_or(0);
+            let numeric = values.clone().all(|value| value.parse::<f64>().is_ok());
+
+            (width, numeric)
+        });
+        let columns: Vec<(usize, bool)> = columns.collect();
+
+        for row in [&self.headers].into_iter().chain(&self.rows) {
+            let line = row
+                .iter()
+                .zip(&columns)
+                .map(|(value, &(width, numeric))| {
+                    if numeric {
+                        format!("{:>width$}", value)
+                    } else {
+                        format!("{:<width$}", value)
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join("  ");
+
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Error for a report `name` that is not one of the `known` reports.
+pub fn unknown_report(name: &str, known: &[&str]) -> Error {
+    let message = match known {
+        [] => format!("unknown report `{}`, this puzzle has no reports", name),
+        known => format!(
+            "unknown report `{}`, expected one of {}",
+            name,
+            known
+                .iter()
+                .map(|name| format!("`{}`", name))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+    };
+
+    Error::new(ErrorKind::Config, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_table_aligns_columns() {
+        let mut table = Table::new(&["rank", "elf", "calories"]);
+        table.push(vec![
+            "1".to_string(),
+            "Elf 4".to_string(),
+            "24000".to_string(),
+        ]);
+        table.push(vec![
+            "2".to_string(),
+            "Elf 10".to_string(),
+            "900".to_string(),
+        ]);
+        let expected = "rank  elf     calories\n   1  Elf 4      24000\n   2  Elf 10       900\n";
+
+        assert_eq!(table.to_string(), expected);
+    }
+
+    #[test]
+    fn unknown_report_lists_known_reports() {
+        let result = unknown_report("ranks", &["ranking", "stats"]);
+
+        assert_eq!(result.kind(), ErrorKind::Config);
+        assert_eq!(
+            result.to_string(),
+            "unknown report `ranks`, expected one of `ranking`, `stats`"
+        );
+    }
+}